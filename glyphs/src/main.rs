@@ -0,0 +1,22 @@
+mod manifest;
+mod render;
+
+use std::path::{Path, PathBuf};
+
+/// The workspace root, so the tool works from any directory.
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("glyphs lives inside the workspace")
+        .to_path_buf()
+}
+
+fn main() {
+    let root = workspace_root();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("render") => render::render_all(&root, args.iter().any(|arg| arg == "--force")),
+        Some("manifest") => manifest::write_manifest(&root),
+        _ => eprintln!("Usage: glyphs render [--force] | glyphs manifest")
+    }
+}
@@ -0,0 +1,85 @@
+use shared::rendering::{file_stem, RenderSupport, RenderingManifest};
+use shared::symbols::SymbolCatalogue;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Package name to the commands MathJax supports from it, parsed from `data/mathjax_commands.txt`.
+fn parse_whitelist(text: &str) -> HashMap<String, HashSet<String>> {
+    let mut whitelist: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut package = None;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            package = Some(name.to_string());
+            continue;
+        }
+        let package = package.clone().expect("Commands should follow a [package] header");
+        whitelist.entry(package)
+            .or_default()
+            .extend(line.split_whitespace().map(str::to_string));
+    }
+    whitelist
+}
+
+/// The control sequences in `command` without backslashes, e.g. `\not\approx` -> `not`, `approx`.
+/// Control symbols like `\{` are their single character.
+fn control_sequences(command: &str) -> Vec<String> {
+    let mut sequences = Vec::new();
+    let mut chars = command.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        let mut name = String::new();
+        while let Some(&next) = chars.peek().filter(|next| next.is_ascii_alphabetic()) {
+            name.push(next);
+            chars.next();
+        }
+        if name.is_empty() {
+            name.extend(chars.next());
+        }
+        sequences.push(name);
+    }
+    sequences
+}
+
+/// Checks every catalogue symbol against the PNG and SVG renders and the MathJax whitelist,
+/// and writes the result to `data/rendering.json`.
+pub fn write_manifest(root: &Path) {
+    let whitelist_path = root.join("data/mathjax_commands.txt");
+    let whitelist = parse_whitelist(&std::fs::read_to_string(&whitelist_path).expect("Failed to read the MathJax whitelist"));
+    let renders = root.join("web/public/symbols");
+
+    let mut manifest = RenderingManifest::default();
+    for symbol in SymbolCatalogue::embedded().iter() {
+        let stem = file_stem(&symbol.id);
+        let png = renders.join(format!("{stem}.png")).exists();
+        let svg = renders.join(format!("{stem}.svg")).exists();
+        // MathJax only typesets math, and only from packages it implements
+        let mathjax = symbol.mathmode && whitelist.get(symbol.package.as_str()).is_some_and(|commands| {
+            control_sequences(&symbol.command).iter().all(|name| commands.contains(name))
+        });
+        manifest.symbols.insert(symbol.id.clone(), RenderSupport { png, svg, mathjax });
+    }
+
+    let missing: Vec<&String> = manifest.symbols.iter()
+        .filter(|(_, support)| !support.png && !support.svg && !support.mathjax)
+        .map(|(key, _)| key)
+        .collect();
+    for key in &missing {
+        println!("[WARN] No way to render {key}");
+    }
+
+    let json = serde_json::to_string_pretty(&manifest).expect("Failed to serialise the manifest");
+    std::fs::write(root.join("data/rendering.json"), json + "\n").expect("Failed to write rendering.json");
+    println!(
+        "Wrote {} symbols: {} MathJax, {} SVG, {} PNG only, {} missing",
+        manifest.symbols.len(),
+        manifest.symbols.values().filter(|support| support.mathjax).count(),
+        manifest.symbols.values().filter(|support| support.svg && !support.mathjax).count(),
+        manifest.symbols.values().filter(|support| support.png && !support.svg && !support.mathjax).count(),
+        missing.len()
+    );
+}
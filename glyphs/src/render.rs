@@ -0,0 +1,111 @@
+use shared::rendering::{file_stem, SVG_ID};
+use shared::symbols::{Symbol, SymbolCatalogue};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// dvipng resolution; high enough to stay sharp when the glyph is shown large
+const PNG_DPI: &str = "600";
+
+/// A standalone document containing just the symbol, cropped to its bounding box.
+fn latex_document(symbol: &Symbol) -> String {
+    let content = if symbol.mathmode {
+        format!("${}$", symbol.command)
+    } else {
+        symbol.command.clone()
+    };
+
+    let mut lines = vec!["\\documentclass[preview]{standalone}".to_string()];
+    lines.extend(symbol.preamble());
+    lines.push("\\begin{document}".to_string());
+    lines.push(content);
+    lines.push("\\end{document}".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn run(program: &str, args: &[&str], directory: &Path) -> Result<Output, String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|error| format!("couldn't run {program}: {error}"))?;
+    if output.status.success() {
+        Ok(output)
+    } else {
+        // LaTeX reports errors on stdout, the converters on stderr
+        let log = [output.stdout.as_slice(), output.stderr.as_slice()].concat();
+        let log = String::from_utf8_lossy(&log);
+        let start = log.char_indices().rev().nth(299).map_or(0, |(index, _)| index);
+        Err(format!("{program} failed:\n{}", &log[start..]))
+    }
+}
+
+/// Typesets `symbol` to DVI in `work_dir`, then converts it to `<stem>.png` and `<stem>.svg` in `out_dir`.
+fn render_symbol(symbol: &Symbol, work_dir: &Path, out_dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(work_dir).map_err(|error| error.to_string())?;
+    std::fs::write(work_dir.join("symbol.tex"), latex_document(symbol)).map_err(|error| error.to_string())?;
+    run("latex", &["-interaction=nonstopmode", "-halt-on-error", "symbol.tex"], work_dir)?;
+
+    let stem = file_stem(&symbol.id);
+    let png = out_dir.join(format!("{stem}.png"));
+    let svg = out_dir.join(format!("{stem}.svg"));
+    let png = png.to_str().ok_or("output path isn't UTF-8")?;
+    let svg = svg.to_str().ok_or("output path isn't UTF-8")?;
+    run("dvipng", &["-D", PNG_DPI, "-T", "tight", "-bg", "Transparent", "-o", png, "symbol.dvi"], work_dir)?;
+    run("dvisvgm", &["--no-fonts", "--exact-bbox", "-o", svg, "symbol.dvi"], work_dir)?;
+
+    let rendered = std::fs::read_to_string(svg).map_err(|error| error.to_string())?;
+    std::fs::write(svg, themeable(&rendered)?).map_err(|error| error.to_string())?;
+    Ok(())
+}
+
+/// Gives the root `<svg>` the id the web app's `<use>` references and fills it with `currentColor`,
+/// so the glyph takes the text colour of wherever it's shown. dvisvgm leaves fills unset, so they inherit it.
+fn themeable(svg: &str) -> Result<String, String> {
+    let start = svg.find("<svg").ok_or("dvisvgm output has no <svg> element")?;
+    let (head, tail) = svg.split_at(start + "<svg".len());
+    Ok(format!("{head} id='{SVG_ID}' fill='currentColor'{tail}"))
+}
+
+/// Renders every catalogue symbol into `web/public/symbols` with `latex`, `dvipng` and `dvisvgm`,
+/// one job per core. Symbols with both outputs already present are skipped unless `force` is set.
+pub fn render_all(root: &Path, force: bool) {
+    let out_dir = root.join("web/public/symbols");
+    std::fs::create_dir_all(&out_dir).expect("Failed to create the output directory");
+    let out_dir = out_dir.canonicalize().expect("Failed to resolve the output directory");
+
+    let symbols: Vec<&Symbol> = SymbolCatalogue::embedded().iter()
+        .filter(|symbol| {
+            let stem = file_stem(&symbol.id);
+            force || !out_dir.join(format!("{stem}.png")).exists() || !out_dir.join(format!("{stem}.svg")).exists()
+        })
+        .collect();
+    println!("Rendering {} symbols", symbols.len());
+
+    let next = AtomicUsize::new(0);
+    let failures = AtomicUsize::new(0);
+    let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let scratch = std::env::temp_dir().join(format!("glyphs-{}", std::process::id()));
+
+    thread::scope(|scope| {
+        for worker in 0..workers {
+            let work_dir: PathBuf = scratch.join(worker.to_string());
+            let (symbols, next, failures, out_dir) = (&symbols, &next, &failures, &out_dir);
+            scope.spawn(move || {
+                while let Some(symbol) = symbols.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    match render_symbol(symbol, &work_dir, out_dir) {
+                        Ok(()) => println!("[OK] {}", symbol.id),
+                        Err(error) => {
+                            failures.fetch_add(1, Ordering::Relaxed);
+                            println!("[ERROR] {}: {error}", symbol.id);
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let _ = std::fs::remove_dir_all(&scratch);
+    println!("Done, {} failed", failures.load(Ordering::Relaxed));
+}
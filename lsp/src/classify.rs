@@ -0,0 +1,119 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use burn::backend::NdArray;
+use burn::backend::ndarray::NdArrayDevice;
+use lsp_types::request::Request;
+use serde::{Deserialize, Serialize};
+use shared::classifier::OfflineClassifier;
+use shared::image_processing::{image_from_bytes, rasterize_strokes};
+use shared::symbols::SymbolCatalogue;
+
+// Training writes these to its artifact directory; copy them to the repository root (see the README)
+static STATE_ENCODED: &[u8] = include_bytes!("../../model.bin");
+static CALIBRATION_ENCODED: &[u8] = include_bytes!("../../calibration.json");
+static LABELS_ENCODED: &[u8] = include_bytes!("../../labels.json");
+
+const MAX_K: usize = 50;
+const DEFAULT_K: usize = 5;
+
+/// `texify/classify`: ranks the catalogue symbols against a drawing, fully offline.
+pub enum Classify {}
+
+impl Request for Classify {
+    type Params = ClassifyParams;
+    type Result = ClassifyResult;
+    const METHOD: &'static str = "texify/classify";
+}
+
+/// Either detexify `strokes` (`[[[x, y, t], ...], ...]`) or a base64 `png` of the symbol.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ClassifyParams {
+    pub strokes: Option<Vec<Vec<[f32; 3]>>>,
+    pub png: Option<String>,
+    pub k: Option<usize>
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RankedSymbolInfo {
+    pub key: String,
+    pub command: String,
+    pub package: String,
+    /// Calibrated, between 0 and 1.
+    pub probability: f32,
+    /// The lines to add to the preamble for the symbol to compile.
+    pub preamble: Vec<String>
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ClassifyResult {
+    /// False when even the best guess falls below the rejection threshold; `symbols` is still ranked.
+    pub recognised: bool,
+    pub symbols: Vec<RankedSymbolInfo>
+}
+
+/// The embedded model; loading takes a moment, so the server only does it on the first request.
+pub fn load_classifier() -> Result<OfflineClassifier<NdArray>, String> {
+    OfflineClassifier::from_bytes(STATE_ENCODED, CALIBRATION_ENCODED, LABELS_ENCODED, NdArrayDevice::default())
+}
+
+/// Answers a classify request, or explains why the params are unusable.
+pub fn classify(classifier: &OfflineClassifier<NdArray>, params: ClassifyParams) -> Result<ClassifyResult, String> {
+    let k = params.k.unwrap_or(DEFAULT_K);
+    if !(1..=MAX_K).contains(&k) {
+        return Err(format!("k must be between 1 and {MAX_K}"));
+    }
+    let image = match (params.strokes, params.png) {
+        (Some(strokes), None) => {
+            if strokes.iter().all(|stroke| stroke.is_empty()) {
+                return Err("strokes is empty".to_string());
+            }
+            rasterize_strokes(&strokes)
+        }
+        (None, Some(png)) => {
+            let bytes = STANDARD.decode(png).map_err(|error| format!("invalid base64: {error}"))?;
+            image_from_bytes(&bytes).map_err(|error| format!("invalid PNG: {error}"))?
+        }
+        _ => return Err("send exactly one of strokes and png".to_string())
+    };
+
+    let ranked = classifier.rank(image, k);
+    let catalogue = SymbolCatalogue::embedded();
+    Ok(ClassifyResult {
+        recognised: classifier.is_recognised(&ranked),
+        symbols: ranked.into_iter()
+            .map(|ranked| {
+                let symbol = catalogue.get(&ranked.key);
+                RankedSymbolInfo {
+                    command: symbol.map(|symbol| symbol.command.clone()).unwrap_or_default(),
+                    package: symbol.map(|symbol| symbol.package.clone()).unwrap_or_default(),
+                    preamble: symbol.map(|symbol| symbol.preamble()).unwrap_or_default(),
+                    key: ranked.key,
+                    probability: ranked.probability
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::backend::NdArray;
+    use burn::backend::ndarray::NdArrayDevice;
+    use shared::classifier::OfflineClassifier;
+
+    const CALIBRATION: &[u8] = br#"{"temperature":1.0,"rejection_threshold":0.5}"#;
+
+    #[test]
+    fn truncated_state_is_an_error() {
+        let loaded = OfflineClassifier::<NdArray>::from_bytes(&[], CALIBRATION, br#"{"keys":["\\alpha"]}"#, NdArrayDevice::default());
+
+        assert!(loaded.is_err_and(|message| message.contains("state")));
+    }
+
+    #[test]
+    fn malformed_labels_are_an_error() {
+        let loaded = OfflineClassifier::<NdArray>::from_bytes(&[], CALIBRATION, b"not json", NdArrayDevice::default());
+
+        assert!(loaded.is_err_and(|message| message.contains("labels")));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::preamble::{control_words, insertion_line, preamble_fixes};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Position, Range, TextEdit, WorkspaceEdit
+};
+use shared::symbols::Symbol;
+use std::collections::{BTreeSet, HashMap};
+
+/// Quick fixes adding the `\usepackage` lines for catalogue commands in the requested range
+/// that no loaded package provides. A command several packages provide gets one fix per package.
+pub fn code_actions(text: &str, params: &CodeActionParams, index: &HashMap<String, Vec<&'static Symbol>>) -> Vec<CodeActionOrCommand> {
+    let range = params.range;
+    let lines = text.lines()
+        .skip(range.start.line as usize)
+        .take(range.end.line.saturating_sub(range.start.line) as usize + 1);
+
+    let mut fixes: BTreeSet<Vec<String>> = BTreeSet::new();
+    for line in lines {
+        // The preamble itself is never missing anything
+        let line = line.split('%').next().unwrap_or_default();
+        if line.trim_start().starts_with("\\usepackage") {
+            continue;
+        }
+        for word in control_words(line) {
+            fixes.extend(preamble_fixes(word, text, index));
+        }
+    }
+
+    let insert_at = Position::new(insertion_line(text), 0);
+    fixes.into_iter()
+        .map(|lines| {
+            let edit = TextEdit::new(Range::new(insert_at, insert_at), lines.iter().map(|line| format!("{line}\n")).collect());
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title: format!("Add {}", lines.join(" and ")),
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(params.text_document.uri.clone(), vec![edit])])),
+                    ..WorkspaceEdit::default()
+                }),
+                ..CodeAction::default()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+use crate::preamble::missing_preamble;
+use crate::text::{line_before, utf16_column};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionList, CompletionParams, CompletionTextEdit, Documentation,
+    MarkupContent, MarkupKind, Position, Range, TextEdit
+};
+use shared::search::search;
+use shared::symbols::{Symbol, SymbolCatalogue};
+
+// Editors filter further as the user types; past this the list is truncated and marked incomplete
+const MAX_ITEMS: usize = 200;
+
+/// Completes the `\name` before the cursor to catalogue commands, best matches first. A command
+/// several packages provide is offered once, listing them all.
+pub fn complete(text: &str, params: &CompletionParams) -> Option<CompletionList> {
+    let position = params.text_document_position.position;
+    let before = line_before(text, position);
+    let start = before.rfind('\\')?;
+    let typed = &before[start + 1..];
+    if !typed.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let range = Range::new(Position::new(position.line, utf16_column(before, start)), position);
+    let mut groups: Vec<(&str, Vec<&Symbol>)> = Vec::new();
+    for symbol in search(SymbolCatalogue::embedded(), typed) {
+        match groups.iter_mut().find(|(command, _)| *command == symbol.command) {
+            Some((_, symbols)) => symbols.push(symbol),
+            None => groups.push((&symbol.command, vec![symbol]))
+        }
+    }
+
+    let is_incomplete = groups.len() > MAX_ITEMS;
+    let items = groups.into_iter()
+        .take(MAX_ITEMS)
+        .enumerate()
+        .map(|(rank, (command, symbols))| completion_item(command, &symbols, text, range, rank))
+        .collect();
+    Some(CompletionList { is_incomplete, items })
+}
+
+fn completion_item(command: &str, symbols: &[&Symbol], text: &str, range: Range, rank: usize) -> CompletionItem {
+    let packages: Vec<&str> = symbols.iter().map(|symbol| symbol.package.as_str()).collect();
+    let modes = match (symbols.iter().any(|s| s.mathmode), symbols.iter().any(|s| s.textmode)) {
+        (true, true) => "math and text",
+        (true, false) => "math",
+        _ => "text"
+    };
+    // Show the preamble the document still lacks, or say it needs none
+    let missing: Vec<String> = symbols.iter()
+        .map(|symbol| missing_preamble(symbol, text))
+        .min_by_key(Vec::len)
+        .unwrap_or_default();
+    let documentation = if missing.is_empty() {
+        "Works with the current preamble.".to_string()
+    } else {
+        format!("Needs:\n```latex\n{}\n```", missing.join("\n"))
+    };
+
+    CompletionItem {
+        label: command.to_string(),
+        kind: Some(CompletionItemKind::CONSTANT),
+        detail: Some(format!("{} ({modes})", packages.join(", "))),
+        documentation: Some(Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value: documentation })),
+        sort_text: Some(format!("{rank:04}")),
+        filter_text: Some(command.to_string()),
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, command.to_string()))),
+        ..CompletionItem::default()
+    }
+}
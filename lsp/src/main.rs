@@ -0,0 +1,137 @@
+mod classify;
+mod code_actions;
+mod completion;
+mod preamble;
+mod text;
+
+use burn::backend::NdArray;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _};
+use lsp_types::request::{CodeActionRequest, Completion, Request as _};
+use lsp_types::{
+    CodeActionProviderCapability, CompletionOptions, ServerCapabilities, TextDocumentSyncCapability,
+    TextDocumentSyncKind, Url
+};
+use serde::de::DeserializeOwned;
+use shared::classifier::OfflineClassifier;
+use shared::symbols::Symbol;
+use std::collections::HashMap;
+
+/// Open documents, the catalogue index for code actions and the model once a classify request needs it.
+struct Server {
+    documents: HashMap<Url, String>,
+    index: HashMap<String, Vec<&'static Symbol>>,
+    classifier: Option<OfflineClassifier<NdArray>>
+}
+
+fn parse<P: DeserializeOwned>(request: &Request) -> Result<P, Response> {
+    serde_json::from_value(request.params.clone())
+        .map_err(|error| Response::new_err(request.id.clone(), ErrorCode::InvalidParams as i32, error.to_string()))
+}
+
+fn ok(id: RequestId, result: impl serde::Serialize) -> Response {
+    Response::new_ok(id, serde_json::to_value(result).expect("Failed to serialise a response"))
+}
+
+impl Server {
+    fn document(&self, uri: &Url) -> &str {
+        self.documents.get(uri).map(String::as_str).unwrap_or_default()
+    }
+
+    fn handle_request(&mut self, request: Request) -> Response {
+        let id = request.id.clone();
+        match request.method.as_str() {
+            Completion::METHOD => match parse::<lsp_types::CompletionParams>(&request) {
+                Ok(params) => {
+                    let text = self.document(&params.text_document_position.text_document.uri);
+                    ok(id, completion::complete(text, &params))
+                }
+                Err(response) => response
+            },
+            CodeActionRequest::METHOD => match parse::<lsp_types::CodeActionParams>(&request) {
+                Ok(params) => {
+                    let text = self.document(&params.text_document.uri);
+                    ok(id, code_actions::code_actions(text, &params, &self.index))
+                }
+                Err(response) => response
+            },
+            classify::Classify::METHOD => match parse::<classify::ClassifyParams>(&request) {
+                Ok(params) => {
+                    if self.classifier.is_none() {
+                        match classify::load_classifier() {
+                            Ok(classifier) => self.classifier = Some(classifier),
+                            Err(message) => return Response::new_err(id, ErrorCode::InternalError as i32, message)
+                        }
+                    }
+                    let classifier = self.classifier.as_ref().expect("The classifier was just loaded");
+                    match classify::classify(classifier, params) {
+                        Ok(result) => ok(id, result),
+                        Err(message) => Response::new_err(id, ErrorCode::InvalidParams as i32, message)
+                    }
+                }
+                Err(response) => response
+            },
+            method => Response::new_err(id, ErrorCode::MethodNotFound as i32, format!("unknown method {method}"))
+        }
+    }
+
+    fn handle_notification(&mut self, notification: Notification) {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Ok(params) = serde_json::from_value::<lsp_types::DidOpenTextDocumentParams>(notification.params) {
+                    self.documents.insert(params.text_document.uri, params.text_document.text);
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                // Full sync, so the last change is the whole document
+                if let Ok(mut params) = serde_json::from_value::<lsp_types::DidChangeTextDocumentParams>(notification.params)
+                    && let Some(change) = params.content_changes.pop()
+                {
+                    self.documents.insert(params.text_document.uri, change.text);
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Ok(params) = serde_json::from_value::<lsp_types::DidCloseTextDocumentParams>(notification.params) {
+                    self.documents.remove(&params.text_document.uri);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["\\".to_string()]),
+            ..CompletionOptions::default()
+        }),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities).expect("Failed to serialise capabilities"))
+        .expect("Failed to initialise the connection");
+
+    let mut server = Server {
+        documents: HashMap::new(),
+        index: preamble::symbols_by_control_word(),
+        classifier: None
+    };
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request).expect("Failed to handle shutdown") {
+                    break;
+                }
+                let response = server.handle_request(request);
+                connection.sender.send(Message::Response(response)).expect("Failed to send a response");
+            }
+            Message::Notification(notification) => server.handle_notification(notification),
+            Message::Response(_) => {}
+        }
+    }
+
+    io_threads.join().expect("Failed to close stdio");
+}
@@ -0,0 +1,124 @@
+use shared::symbols::{Symbol, SymbolCatalogue};
+use std::collections::{BTreeSet, HashMap};
+
+/// A `\usepackage[options]{name}` pair; a single `\usepackage{a,b}` yields one per package.
+#[derive(Clone, Debug, PartialEq)]
+struct PackageUse {
+    name: String,
+    options: Vec<String>
+}
+
+/// Every `\usepackage` in `text`, ignoring anything after a `%` comment.
+fn package_uses(text: &str) -> Vec<PackageUse> {
+    let mut uses = Vec::new();
+    for line in text.lines() {
+        let line = line.split('%').next().unwrap_or_default();
+        let mut rest = line;
+        while let Some(start) = rest.find("\\usepackage") {
+            rest = &rest[start + "\\usepackage".len()..];
+            let mut options = Vec::new();
+            if let Some(after) = rest.trim_start().strip_prefix('[') {
+                let Some(end) = after.find(']') else { break };
+                options = after[..end].split(',').map(|option| option.trim().to_string()).collect();
+                rest = &after[end + 1..];
+            }
+            let Some(after) = rest.trim_start().strip_prefix('{') else { continue };
+            let Some(end) = after.find('}') else { break };
+            for name in after[..end].split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                uses.push(PackageUse { name: name.to_string(), options: options.clone() });
+            }
+            rest = &after[end + 1..];
+        }
+    }
+    uses
+}
+
+/// The lines of `symbol.preamble()` that `text` doesn't already satisfy.
+pub fn missing_preamble(symbol: &Symbol, text: &str) -> Vec<String> {
+    let loaded = package_uses(text);
+    symbol.preamble()
+        .into_iter()
+        .filter(|line| {
+            package_uses(line).iter().any(|needed| {
+                !loaded.iter().any(|have| {
+                    have.name == needed.name && needed.options.iter().all(|option| have.options.contains(option))
+                })
+            })
+        })
+        .collect()
+}
+
+/// The catalogue's commands by control word, e.g. `\mathbb` for `\mathbb{R}`; a command can
+/// come from several packages.
+pub fn symbols_by_control_word() -> HashMap<String, Vec<&'static Symbol>> {
+    let mut index: HashMap<String, Vec<&'static Symbol>> = HashMap::new();
+    for symbol in SymbolCatalogue::embedded().iter() {
+        let word = symbol.command.split('{').next().unwrap_or_default();
+        if word.starts_with('\\') && word.len() > 1 {
+            index.entry(word.to_string()).or_default().push(symbol);
+        }
+    }
+    index
+}
+
+/// The `\name` control words in `line`.
+pub fn control_words(line: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('\\') {
+        let after = &rest[start + 1..];
+        let length = after.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(after.len());
+        if length > 0 {
+            words.push(&rest[start..start + 1 + length]);
+        }
+        rest = &after[length.max(1).min(after.len())..];
+    }
+    words
+}
+
+/// The distinct sets of preamble lines that would make `word` compile, or nothing if it
+/// already does, i.e. some package providing it is loaded or it needs none.
+pub fn preamble_fixes(word: &str, text: &str, index: &HashMap<String, Vec<&'static Symbol>>) -> BTreeSet<Vec<String>> {
+    let Some(candidates) = index.get(word) else { return BTreeSet::new() };
+    let missing: Vec<Vec<String>> = candidates.iter().map(|symbol| missing_preamble(symbol, text)).collect();
+    if missing.iter().any(Vec::is_empty) {
+        return BTreeSet::new();
+    }
+    missing.into_iter().collect()
+}
+
+/// The line new `\usepackage` lines go on: after the last one, else after `\documentclass`, else the top.
+pub fn insertion_line(text: &str) -> u32 {
+    let lines: Vec<&str> = text.lines().collect();
+    let last_use = lines.iter().rposition(|line| line.trim_start().starts_with("\\usepackage"));
+    let class = lines.iter().position(|line| line.trim_start().starts_with("\\documentclass"));
+    last_use.or(class).map_or(0, |line| line as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{control_words, package_uses, PackageUse};
+
+    fn package(name: &str, options: &[&str]) -> PackageUse {
+        PackageUse { name: name.to_string(), options: options.iter().map(|option| option.to_string()).collect() }
+    }
+
+    #[test]
+    fn package_uses_split_lists_and_share_options() {
+        let uses = package_uses("\\usepackage[opt, other]{a,b}\n\\usepackage{c}");
+
+        assert_eq!(uses, vec![package("a", &["opt", "other"]), package("b", &["opt", "other"]), package("c", &[])]);
+    }
+
+    #[test]
+    fn package_uses_ignore_comments() {
+        let uses = package_uses("% \\usepackage{a}\n\\usepackage{b} % \\usepackage{c}");
+
+        assert_eq!(uses, vec![package("b", &[])]);
+    }
+
+    #[test]
+    fn control_words_stop_at_non_letters() {
+        assert_eq!(control_words("$\\mathbb{R} \\to \\alpha_1\\\\ \\$"), vec!["\\mathbb", "\\to", "\\alpha"]);
+    }
+}
@@ -0,0 +1,42 @@
+use lsp_types::Position;
+
+/// The byte offset of the UTF-16 `character` in `line`, clamped to its end.
+pub fn byte_offset(line: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (offset, c) in line.char_indices() {
+        if units >= character as usize {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+/// The UTF-16 column of the byte `offset` in `line`, which LSP positions count in.
+pub fn utf16_column(line: &str, offset: usize) -> u32 {
+    line[..offset].encode_utf16().count() as u32
+}
+
+/// The text of line `position.line` up to the cursor.
+pub fn line_before(text: &str, position: Position) -> &str {
+    let line = text.lines().nth(position.line as usize).unwrap_or_default();
+    &line[..byte_offset(line, position.character)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_offset, utf16_column};
+
+    #[test]
+    fn columns_count_utf16_units() {
+        // "é" is 2 bytes and 1 unit, "𝔸" is 4 bytes and 2 units
+        let line = "é𝔸x";
+
+        assert_eq!(byte_offset(line, 1), 2);
+        assert_eq!(byte_offset(line, 3), 6);
+        assert_eq!(byte_offset(line, 10), line.len());
+        assert_eq!(utf16_column(line, 2), 1);
+        assert_eq!(utf16_column(line, 6), 3);
+        assert_eq!(utf16_column(line, line.len()), 4);
+    }
+}
@@ -0,0 +1,66 @@
+use burn::config::Config;
+use burn::prelude::Backend;
+use burn::tensor::activation::{log_softmax, softmax};
+use burn::Tensor;
+
+// Top-1 probability below which a drawing is reported as not recognised, unless training overrides it
+pub const DEFAULT_REJECTION_THRESHOLD: f64 = 0.2;
+
+/// Post-hoc calibration fitted on the validation split after training.
+#[derive(Config, Debug)]
+pub struct CalibrationConfig {
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    #[config(default = "DEFAULT_REJECTION_THRESHOLD")]
+    pub rejection_threshold: f64
+}
+
+impl CalibrationConfig {
+    pub fn probabilities<B: Backend, const D: usize>(&self, logits: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        softmax(logits.div_scalar(self.temperature), dim)
+    }
+
+    pub fn is_recognised(&self, top_probability: f64) -> bool {
+        top_probability >= self.rejection_threshold
+    }
+}
+
+/// Mean negative log-likelihood of `targets` under `logits / temperature`.
+pub fn negative_log_likelihood<B: Backend>(logits: Tensor<B, 2>, targets: &[usize], temperature: f64) -> f64 {
+    let log_probabilities = log_softmax(logits.div_scalar(temperature), 1)
+        .into_data()
+        .convert::<f32>()
+        .to_vec::<f32>()
+        .unwrap();
+    let num_classes = log_probabilities.len() / targets.len().max(1);
+
+    let total: f64 = targets.iter()
+        .enumerate()
+        .map(|(i, &target)| -log_probabilities[i * num_classes + target] as f64)
+        .sum();
+
+    total / targets.len().max(1) as f64
+}
+
+/// Expected calibration error over equal-width confidence bins.
+pub fn expected_calibration_error(confidences: &[f32], correct: &[bool], num_bins: usize) -> f64 {
+    let mut bin_counts = vec![0usize; num_bins];
+    let mut bin_confidence = vec![0.0f64; num_bins];
+    let mut bin_accuracy = vec![0.0f64; num_bins];
+
+    for (&confidence, &is_correct) in confidences.iter().zip(correct) {
+        let bin = ((confidence as f64 * num_bins as f64) as usize).min(num_bins - 1);
+        bin_counts[bin] += 1;
+        bin_confidence[bin] += confidence as f64;
+        bin_accuracy[bin] += if is_correct { 1.0 } else { 0.0 };
+    }
+
+    let total = confidences.len().max(1) as f64;
+    (0..num_bins)
+        .filter(|&bin| bin_counts[bin] > 0)
+        .map(|bin| {
+            let count = bin_counts[bin] as f64;
+            (count / total) * (bin_accuracy[bin] / count - bin_confidence[bin] / count).abs()
+        })
+        .sum()
+}
@@ -0,0 +1,84 @@
+use crate::calibration::CalibrationConfig;
+use crate::item::{HEIGHT, WIDTH};
+use crate::labels::Labels;
+use crate::model::{Model, ModelConfig, HIDDEN_SIZE};
+use burn::config::Config;
+use burn::module::Module;
+use burn::prelude::{Backend, Int};
+use burn::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+use burn::Tensor;
+use serde::{Deserialize, Serialize};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// A key with its calibrated probability, between 0 and 1.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RankedSymbol {
+    pub key: String,
+    pub probability: f32
+}
+
+/// Calibrated probabilities and indices of the `k` likeliest classes in each row of `logits`, best first.
+pub fn top_k<B: Backend>(calibration: &CalibrationConfig, logits: Tensor<B, 2>, k: usize) -> (Tensor<B, 2>, Tensor<B, 2, Int>) {
+    let probabilities = calibration.probabilities(logits, 1);
+    let k = k.clamp(1, probabilities.dims()[1]);
+
+    probabilities.topk_with_indices(k, 1)
+}
+
+/// Names one row of `top_k` results; indices past the trained classes are custom symbols, in order.
+pub fn name_ranked(indices: &[i64], probabilities: &[f32], keys: &[String], custom_keys: &[String]) -> Vec<RankedSymbol> {
+    indices.iter()
+        .zip(probabilities)
+        .map(|(&index, &probability)| {
+            let index = index as usize;
+            let key = match keys.get(index) {
+                Some(key) => key.clone(),
+                None => custom_keys[index - keys.len()].clone()
+            };
+            RankedSymbol { key, probability }
+        })
+        .collect()
+}
+
+/// The trained model with its labels and calibration, for native tools that classify synchronously.
+pub struct OfflineClassifier<B: Backend> {
+    model: Model<B>,
+    calibration: CalibrationConfig,
+    labels: Labels,
+    device: B::Device
+}
+
+impl<B: Backend> OfflineClassifier<B> {
+    /// Decodes the `model.bin`, `calibration.json` and `labels.json` artifacts written by training, or says which one is unusable.
+    pub fn from_bytes(model: &[u8], calibration: &[u8], labels: &[u8], device: B::Device) -> Result<Self, String> {
+        let labels = Labels::load_binary(labels).map_err(|error| format!("Failed to decode labels: {error}"))?;
+        let calibration = CalibrationConfig::load_binary(calibration)
+            .map_err(|error| format!("Failed to decode calibration: {error}"))?;
+        // The bincode recorder and `load_record` panic rather than report truncated or mis-shaped state.
+        let model = catch_unwind(AssertUnwindSafe(|| {
+            let record = BinBytesRecorder::<FullPrecisionSettings>::default()
+                .load(model.to_vec(), &device)
+                .expect("Failed to decode state");
+            ModelConfig::new(labels.len(), HIDDEN_SIZE)
+                .init(&device)
+                .load_record(record)
+        })).map_err(|_| format!("Failed to decode state for {} labels", labels.len()))?;
+
+        Ok(Self { model, calibration, labels, device })
+    }
+
+    /// The `k` most likely keys, best first, without applying the rejection threshold.
+    pub fn rank(&self, image: [[f32; WIDTH]; HEIGHT], k: usize) -> Vec<RankedSymbol> {
+        let tensor = Tensor::<B, 2>::from_floats(image, &self.device).unsqueeze();
+        let (values, indices) = top_k(&self.calibration, self.model.forward(tensor), k);
+        let values = values.into_data().convert::<f32>().to_vec::<f32>().expect("Probabilities should be floats");
+        let indices = indices.into_data().convert::<i64>().to_vec::<i64>().expect("Indices should be integers");
+
+        name_ranked(&indices, &values, &self.labels.keys, &[])
+    }
+
+    /// Whether the best of `ranked` clears the calibrated rejection threshold.
+    pub fn is_recognised(&self, ranked: &[RankedSymbol]) -> bool {
+        ranked.first().is_some_and(|best| self.calibration.is_recognised(best.probability as f64))
+    }
+}
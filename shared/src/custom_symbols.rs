@@ -0,0 +1,89 @@
+use crate::prototypes::mean_prototype;
+use burn::config::Config;
+use burn::prelude::{Backend, TensorData};
+use burn::Tensor;
+
+pub const MIN_EXAMPLES: usize = 3;
+pub const MAX_EXAMPLES: usize = 10;
+// Keys of user-defined symbols are "custom-_<command>", mirroring the detexify key layout
+pub const CUSTOM_KEY_PREFIX: &str = "custom-_";
+
+/// A symbol registered by drawing a few examples, stored as normalised embeddings.
+#[derive(Config, Debug)]
+pub struct CustomSymbol {
+    pub command: String,
+    #[config(default = "Vec::new()")]
+    pub examples: Vec<Vec<f32>>
+}
+
+impl CustomSymbol {
+    pub fn key(&self) -> String {
+        format!("{CUSTOM_KEY_PREFIX}{}", self.command.trim_start_matches('\\'))
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.examples.len() >= MIN_EXAMPLES
+    }
+
+    pub fn prototype(&self) -> Vec<f32> {
+        mean_prototype(&self.examples)
+    }
+}
+
+/// A stable FNV-1a hash of a `model.bin`, identifying which model a library's embeddings came from.
+pub fn model_fingerprint(model: &[u8]) -> String {
+    let hash = model.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100_0000_01b3));
+    format!("{hash:016x}")
+}
+
+#[derive(Config, Debug)]
+pub struct CustomSymbolLibrary {
+    // `model_fingerprint` of the model that embedded the examples
+    pub model: Option<String>,
+    #[config(default = "Vec::new()")]
+    pub symbols: Vec<CustomSymbol>
+}
+
+impl CustomSymbolLibrary {
+    /// Records another example of `command`, creating the symbol on first use.
+    /// Returns false once the symbol already has `MAX_EXAMPLES`.
+    pub fn add_example(&mut self, command: &str, embedding: Vec<f32>) -> bool {
+        let command = format!("\\{}", command.trim().trim_start_matches('\\'));
+        match self.symbols.iter_mut().find(|symbol| symbol.command == command) {
+            Some(symbol) if symbol.examples.len() >= MAX_EXAMPLES => false,
+            Some(symbol) => {
+                symbol.examples.push(embedding);
+                true
+            }
+            None => {
+                self.symbols.push(CustomSymbol::new(command).with_examples(vec![embedding]));
+                true
+            }
+        }
+    }
+
+    pub fn remove(&mut self, command: &str) {
+        self.symbols.retain(|symbol| symbol.command != command);
+    }
+
+    /// Symbols with enough examples to take part in classification.
+    pub fn ready(&self) -> Vec<&CustomSymbol> {
+        self.symbols.iter().filter(|symbol| symbol.is_ready()).collect()
+    }
+
+    /// Whether the examples were embedded by the model with `fingerprint`, as `dimension`-long
+    /// vectors; embeddings from any other model, even one of the same size, are meaningless.
+    pub fn is_compatible(&self, fingerprint: &str, dimension: usize) -> bool {
+        self.model.as_deref() == Some(fingerprint)
+            && self.symbols.iter().all(|symbol| symbol.examples.iter().all(|example| example.len() == dimension))
+    }
+
+    /// One prototype row per ready symbol, or `None` if there are none.
+    pub fn prototypes<B: Backend>(&self, device: &B::Device) -> Option<Tensor<B, 2>> {
+        let ready = self.ready();
+        let dimension = ready.first()?.examples[0].len();
+        let values: Vec<f32> = ready.iter().flat_map(|symbol| symbol.prototype()).collect();
+
+        Some(Tensor::from_data(TensorData::new(values, [ready.len(), dimension]).convert::<B::FloatElem>(), device))
+    }
+}
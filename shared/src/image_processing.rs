@@ -1,5 +1,7 @@
 use crate::item::{HEIGHT, WIDTH};
-use image::{GrayImage, Luma};
+use image::imageops::{self, FilterType};
+use image::{GrayImage, ImageReader, ImageResult, Limits, Luma};
+use std::io::Cursor;
 pub fn rasterize_strokes(
     strokes: &Vec<Vec<[f32; 3]>>
 ) -> [[f32; WIDTH]; HEIGHT] {
@@ -19,13 +21,15 @@ pub fn rasterize_strokes(
         }
     }
 
-    let width = max_x - min_x;
-    let height = max_y - min_y;
-
-    if width <= 0.0 || height <= 0.0 {
+    // No points at all
+    if min_x > max_x {
         return [[0.0; WIDTH]; HEIGHT];
     }
 
+    // A dot, `-` or `|` has no extent along an axis: treat it as one unit wide, centred in the frame
+    let (width, offset_x) = if max_x > min_x { (max_x - min_x, 0.0) } else { (1.0, (WIDTH / 2) as f32) };
+    let (height, offset_y) = if max_y > min_y { (max_y - min_y, 0.0) } else { (1.0, (HEIGHT / 2) as f32) };
+
     // 2. Compute scale (preserve aspect ratio)
     let scale = (WIDTH as f32 / width).min(HEIGHT as f32 / height);
 
@@ -42,15 +46,17 @@ pub fn rasterize_strokes(
 
     // 4. Draw lines with Bresenham
     for stroke in strokes {
-        for window in stroke.windows(2) {
-            let [x0, y0, _] = window[0];
-            let [x1, y1, _] = window[1];
+        // A single-point stroke is a dot, drawn as a segment of length zero
+        let dot = stroke.first().filter(|_| stroke.len() == 1).map(|&point| (point, point));
+        for (start, end) in stroke.windows(2).map(|window| (window[0], window[1])).chain(dot) {
+            let [x0, y0, _] = start;
+            let [x1, y1, _] = end;
 
             // Transform to pixel space
-            let mut px0 = ((x0 - min_x) * scale) as isize;
-            let mut py0 = ((y0 - min_y) * scale) as isize;
-            let px1 = ((x1 - min_x) * scale) as isize;
-            let py1 = ((y1 - min_y) * scale) as isize;
+            let mut px0 = ((x0 - min_x) * scale + offset_x) as isize;
+            let mut py0 = ((y0 - min_y) * scale + offset_y) as isize;
+            let px1 = ((x1 - min_x) * scale + offset_x) as isize;
+            let py1 = ((y1 - min_y) * scale + offset_y) as isize;
 
             // Bresenham’s line algorithm
             let dx = (px1 - px0).abs();
@@ -104,4 +110,99 @@ pub fn save_image(img: &[[f32; WIDTH]; HEIGHT], label: String) {
     }
 
     im.save(label + ".png").unwrap();
-}
\ No newline at end of file
+}
+
+// Far beyond any symbol crop; stops a small file declaring huge dimensions from exhausting memory
+const MAX_DECODED_SIDE: u32 = 4096;
+
+/// Reads a drawing from an encoded image, dark ink on a light or transparent background,
+/// cropped to the ink and scaled into the model's input the way `rasterize_strokes` places strokes.
+pub fn image_from_bytes(bytes: &[u8]) -> ImageResult<[[f32; WIDTH]; HEIGHT]> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DECODED_SIDE);
+    limits.max_image_height = Some(MAX_DECODED_SIDE);
+    let mut reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+    reader.limits(limits);
+    let decoded = reader.decode()?.to_luma_alpha8();
+    let ink = GrayImage::from_fn(decoded.width(), decoded.height(), |x, y| {
+        let [luma, alpha] = decoded.get_pixel(x, y).0;
+        Luma([((255 - luma) as u16 * alpha as u16 / 255) as u8])
+    });
+
+    // Faint pixels are scanner or antialiasing noise, not ink
+    let inked = ink.enumerate_pixels().filter(|(_, _, pixel)| pixel.0[0] > 32);
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+    for (x, y, _) in inked {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let mut img = [[0.0f32; WIDTH]; HEIGHT];
+    if min_x > max_x {
+        return Ok(img);
+    }
+
+    let (width, height) = (max_x - min_x + 1, max_y - min_y + 1);
+    let scale = (WIDTH as f32 / width as f32).min(HEIGHT as f32 / height as f32);
+    let cropped = imageops::crop_imm(&ink, min_x, min_y, width, height).to_image();
+    let resized = imageops::resize(
+        &cropped,
+        ((width as f32 * scale) as u32).clamp(1, WIDTH as u32),
+        ((height as f32 * scale) as u32).clamp(1, HEIGHT as u32),
+        FilterType::Triangle
+    );
+
+    for (x, y, pixel) in resized.enumerate_pixels() {
+        img[y as usize][x as usize] = pixel.0[0] as f32 / 255.0;
+    }
+    Ok(img)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rasterize_strokes;
+    use crate::item::{HEIGHT, WIDTH};
+
+    /// `(x, y)` of every inked pixel.
+    fn inked(image: &[[f32; WIDTH]; HEIGHT]) -> Vec<(usize, usize)> {
+        (0..HEIGHT)
+            .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+            .filter(|&(x, y)| image[y][x] > 0.0)
+            .collect()
+    }
+
+    #[test]
+    fn no_points_give_a_blank_image() {
+        assert!(inked(&rasterize_strokes(&vec![])).is_empty());
+        assert!(inked(&rasterize_strokes(&vec![vec![]])).is_empty());
+    }
+
+    #[test]
+    fn a_dot_is_drawn_in_the_centre() {
+        let pixels = inked(&rasterize_strokes(&vec![vec![[3.0, 7.0, 0.0]]]));
+
+        assert!(pixels.contains(&(WIDTH / 2, HEIGHT / 2)));
+        assert!(pixels.iter().all(|&(x, y)| x.abs_diff(WIDTH / 2) <= 1 && y.abs_diff(HEIGHT / 2) <= 1));
+    }
+
+    #[test]
+    fn a_horizontal_line_spans_the_width_at_mid_height() {
+        let pixels = inked(&rasterize_strokes(&vec![vec![[0.0, 5.0, 0.0], [10.0, 5.0, 1.0]]]));
+
+        assert!((0..WIDTH).all(|x| pixels.contains(&(x, HEIGHT / 2))));
+        assert!(pixels.iter().all(|&(_, y)| y.abs_diff(HEIGHT / 2) <= 1));
+    }
+
+    #[test]
+    fn a_wide_box_keeps_its_aspect_ratio() {
+        let outline = vec![vec![
+            [0.0, 0.0, 0.0], [20.0, 0.0, 1.0], [20.0, 10.0, 2.0], [0.0, 10.0, 3.0], [0.0, 0.0, 4.0]
+        ]];
+        let pixels = inked(&rasterize_strokes(&outline));
+
+        // Scaled to fill the width, so it covers only the top half
+        assert!(pixels.iter().any(|&(x, _)| x >= WIDTH - 2));
+        assert!(pixels.iter().all(|&(_, y)| y <= HEIGHT / 2 + 1));
+    }
+}
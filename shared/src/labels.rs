@@ -0,0 +1,21 @@
+use burn::config::Config;
+
+/// Detexify keys in model output order; index `i` is the key of logit `i`.
+#[derive(Config, Debug)]
+pub struct Labels {
+    pub keys: Vec<String>
+}
+
+impl Labels {
+    pub fn index_of(&self, key: &str) -> Option<usize> {
+        self.keys.iter().position(|k| k == key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
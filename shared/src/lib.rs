@@ -1,4 +1,13 @@
 pub mod basicblock;
 pub mod model;
 pub mod item;
-pub mod image_processing;
\ No newline at end of file
+pub mod image_processing;
+pub mod calibration;
+pub mod prototypes;
+pub mod custom_symbols;
+pub mod labels;
+pub mod symbols;
+pub mod search;
+pub mod unicode;
+pub mod rendering;
+pub mod classifier;
@@ -1,11 +1,14 @@
 use crate::basicblock::BasicBlock;
 use burn::config::Config;
-use burn::module::Module;
+use burn::module::{Module, Param};
 use burn::nn::pool::{MaxPool2d, MaxPool2dConfig};
 use burn::nn::{Dropout, DropoutConfig, Linear, LinearConfig, Relu};
 use burn::prelude::Backend;
 use burn::Tensor;
 
+// Width of the trained model's hidden layer, and so the length of its embeddings
+pub const HIDDEN_SIZE: usize = 256;
+
 #[derive(Module, Debug)]
 pub struct Model<B: Backend> {
     basicblock1: BasicBlock<B>,
@@ -23,7 +26,7 @@ pub struct Model<B: Backend> {
 }
 
 impl<B: Backend> Model<B> {
-    pub fn forward(&self, images: Tensor<B, 3>) -> Tensor<B, 2> {
+    fn features(&self, images: Tensor<B, 3>) -> Tensor<B, 2> {
         let [batch_size, height, width] = images.dims();
 
         let x = images.reshape([batch_size, 1, height, width]);
@@ -36,21 +39,98 @@ impl<B: Backend> Model<B> {
         let x = self.pool3.forward(x);
         let x = self.basicblock4.forward(x);
         let x = self.pool4.forward(x);
-        let x = x.reshape([batch_size, 256 * 2 * 2]);
+
+        x.reshape([batch_size, 256 * 2 * 2])
+    }
+
+    pub fn forward(&self, images: Tensor<B, 3>) -> Tensor<B, 2> {
+        let x = self.features(images);
         let x = self.linear1.forward(x);
         let x = self.dropout.forward(x);
         let x = self.activation.forward(x);
 
         self.linear2.forward(x)
     }
+
+    fn hidden(&self, images: Tensor<B, 3>) -> Tensor<B, 2> {
+        let x = self.features(images);
+        let x = self.linear1.forward(x);
+
+        self.activation.forward(x)
+    }
+
+    /// Penultimate-layer activations, L2-normalised so that dot products are cosine similarities.
+    pub fn embed(&self, images: Tensor<B, 3>) -> Tensor<B, 2> {
+        let x = self.hidden(images);
+
+        let norm = x.clone()
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .sqrt()
+            .clamp_min(1e-12);
+
+        x / norm
+    }
+
+    /// `linear2` weights and biases for extra classes imprinted from unit-length `prototypes` rows.
+    ///
+    /// Each prototype is scaled to the average norm (and given the average bias) of the trained
+    /// rows, so the extra logits are on the same scale as the existing ones.
+    fn imprinted_weights(&self, prototypes: Tensor<B, 2>) -> (Tensor<B, 2>, Option<Tensor<B, 1>>) {
+        let [num_prototypes, _] = prototypes.dims();
+
+        let weight_norm = self.linear2.weight.val()
+            .powf_scalar(2.0)
+            .sum_dim(0)
+            .sqrt()
+            .mean()
+            .unsqueeze::<2>();
+        let weights = prototypes.transpose() * weight_norm;
+        let biases = self.linear2.bias
+            .as_ref()
+            .map(|bias| bias.val().mean().repeat_dim(0, num_prototypes));
+
+        (weights, biases)
+    }
+
+    /// Logits for the trained classes followed by one logit per unit-length `prototypes` row.
+    pub fn forward_with_prototypes(&self, images: Tensor<B, 3>, prototypes: Tensor<B, 2>) -> Tensor<B, 2> {
+        let hidden = self.hidden(images);
+        let logits = self.linear2.forward(hidden.clone());
+
+        let (weights, biases) = self.imprinted_weights(prototypes);
+        let imprinted = hidden.matmul(weights);
+        let imprinted = match biases {
+            Some(biases) => imprinted + biases.unsqueeze::<2>(),
+            None => imprinted
+        };
+
+        Tensor::cat(vec![logits, imprinted], 1)
+    }
+
+    /// Grows `linear2` by one output per `prototypes` row, keeping the existing rows unchanged.
+    pub fn expand_classes(mut self, prototypes: Tensor<B, 2>) -> Self {
+        let (weights, biases) = self.imprinted_weights(prototypes);
+
+        let weight = Tensor::cat(vec![self.linear2.weight.val(), weights], 1).detach();
+        let bias = match (&self.linear2.bias, biases) {
+            (Some(bias), Some(biases)) => Some(Tensor::cat(vec![bias.val(), biases], 0).detach()),
+            _ => None
+        };
+
+        self.linear2.weight = Param::from_tensor(weight);
+        self.linear2.bias = bias.map(Param::from_tensor);
+
+        self
+    }
 }
 
 #[derive(Config, Debug)]
 pub struct ModelConfig {
-    num_classes: usize,
-    hidden_size: usize,
+    pub num_classes: usize,
+    pub hidden_size: usize,
     #[config(default = "0.3")]
-    dropout: f64
+    pub dropout: f64
 }
 
 
@@ -0,0 +1,70 @@
+use burn::config::Config;
+use serde::{Deserialize, Serialize};
+
+/// Per-class mean embeddings, used for "looks like" queries by cosine similarity.
+#[derive(Config, Debug)]
+pub struct PrototypeIndex {
+    pub keys: Vec<String>,
+    pub prototypes: Vec<Vec<f32>>
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Neighbour {
+    pub key: String,
+    pub similarity: f32
+}
+
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    dot / (norm_a * norm_b).max(1e-12)
+}
+
+/// Mean of `embeddings`, re-normalised to unit length.
+pub fn mean_prototype(embeddings: &[Vec<f32>]) -> Vec<f32> {
+    let dimension = embeddings.first().map(|e| e.len()).unwrap_or(0);
+    let mut mean = vec![0.0f32; dimension];
+    for embedding in embeddings {
+        for (m, x) in mean.iter_mut().zip(embedding) {
+            *m += x;
+        }
+    }
+
+    let norm = mean.iter().map(|x| x * x).sum::<f32>().sqrt().max(1e-12);
+    mean.iter().map(|x| x / norm).collect()
+}
+
+impl PrototypeIndex {
+    pub fn position(&self, key: &str) -> Option<usize> {
+        self.keys.iter().position(|k| k == key)
+    }
+
+    /// The `k` classes whose prototypes are most similar to `embedding`.
+    pub fn nearest(&self, embedding: &[f32], k: usize) -> Vec<Neighbour> {
+        self.ranked(embedding, k, None)
+    }
+
+    /// The `k` classes most similar to `key`, excluding `key` itself.
+    pub fn nearest_to_class(&self, key: &str, k: usize) -> Vec<Neighbour> {
+        match self.position(key) {
+            Some(index) => self.ranked(&self.prototypes[index], k, Some(index)),
+            None => Vec::new()
+        }
+    }
+
+    fn ranked(&self, embedding: &[f32], k: usize, exclude: Option<usize>) -> Vec<Neighbour> {
+        let mut scored: Vec<(usize, f32)> = self.prototypes.iter()
+            .enumerate()
+            .filter(|(i, prototype)| Some(*i) != exclude && !prototype.is_empty())
+            .map(|(i, prototype)| (i, cosine_similarity(embedding, prototype)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        scored.into_iter()
+            .take(k)
+            .map(|(i, similarity)| Neighbour { key: self.keys[i].clone(), similarity })
+            .collect()
+    }
+}
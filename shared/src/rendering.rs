@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+static MANIFEST_JSON: &str = include_str!("../../data/rendering.json");
+
+/// The id of the root element of every SVG render, for referencing it with `<use href="...#glyph">`.
+pub const SVG_ID: &str = "glyph";
+
+/// The name renders of `key` are saved under, without extension; keys can contain `/` and `\`.
+pub fn file_stem(key: &str) -> String {
+    key.replace('/', "_slash_").replace('\\', "_backslash_")
+}
+
+/// Percent-encodes everything but unreserved URL characters; keys contain `#`, `%`, `\\` and brackets.
+pub fn percent_encode(text: &str) -> String {
+    let mut encoded = String::new();
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// The URL of the render of `key` with `extension`, e.g. `svg`.
+pub fn render_url(key: &str, extension: &str) -> String {
+    format!("/symbols/{}.{extension}", percent_encode(&file_stem(key)))
+}
+
+/// How a symbol can be shown on the web.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RenderSupport {
+    /// `web/public/symbols/<file_stem>.png` exists.
+    pub png: bool,
+    /// `web/public/symbols/<file_stem>.svg` exists, filled with `currentColor`.
+    #[serde(default)]
+    pub svg: bool,
+    /// MathJax knows the package and every command the symbol uses.
+    pub mathjax: bool
+}
+
+/// Render support for every catalogue key, generated into `data/rendering.json` by `cargo run -p glyphs -- manifest`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RenderingManifest {
+    pub symbols: BTreeMap<String, RenderSupport>
+}
+
+impl RenderingManifest {
+    /// The manifest compiled into the binary.
+    pub fn embedded() -> &'static RenderingManifest {
+        static MANIFEST: OnceLock<RenderingManifest> = OnceLock::new();
+        MANIFEST.get_or_init(|| serde_json::from_str(MANIFEST_JSON).expect("rendering.json should be valid"))
+    }
+
+    /// Support for the catalogue key `key`; unknown keys have neither render.
+    pub fn get(&self, key: &str) -> RenderSupport {
+        self.symbols.get(key).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_url, RenderingManifest};
+    use crate::symbols::SymbolCatalogue;
+
+    #[test]
+    fn render_urls_escape_fragment_and_percent_signs() {
+        assert_eq!(render_url("latex2e-OT1-_#", "svg"), "/symbols/latex2e-OT1-_%23.svg");
+        assert_eq!(render_url("latex2e-OT1-_%", "png"), "/symbols/latex2e-OT1-_%25.png");
+        assert_eq!(render_url("latex2e-OT1-_\\{", "png"), "/symbols/latex2e-OT1-__backslash_%7B.png");
+    }
+
+    #[test]
+    fn manifest_covers_exactly_the_catalogue() {
+        let catalogue = SymbolCatalogue::embedded();
+        let manifest = RenderingManifest::embedded();
+
+        let unrendered: Vec<&String> = catalogue.iter()
+            .map(|symbol| &symbol.id)
+            .filter(|key| !manifest.symbols.contains_key(*key))
+            .collect();
+        let unknown: Vec<&String> = manifest.symbols.keys()
+            .filter(|key| catalogue.get(key).is_none())
+            .collect();
+
+        assert!(unrendered.is_empty(), "Keys missing from rendering.json: {unrendered:?}");
+        assert!(unknown.is_empty(), "Keys in rendering.json but not symbols.json: {unknown:?}");
+    }
+}
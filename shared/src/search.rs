@@ -0,0 +1,206 @@
+use crate::symbols::{Symbol, SymbolCatalogue};
+
+const GREEK_LETTERS: [&str; 24] = [
+    "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa", "lambda", "mu",
+    "nu", "xi", "omicron", "pi", "rho", "sigma", "tau", "upsilon", "phi", "chi", "psi", "omega"
+];
+
+const ZODIAC_SIGNS: [&str; 12] = [
+    "aries", "taurus", "gemini", "cancer", "leo", "virgo",
+    "libra", "scorpio", "sagittarius", "capricorn", "aquarius", "pisces"
+];
+
+const PLANETS: [&str; 10] = [
+    "mercury", "venus", "earth", "mars", "jupiter", "saturn", "uranus", "neptune", "pluto", "sun"
+];
+
+// Names after stripping a `text` or `math` prefix, e.g. `\textsterling`
+const CURRENCIES: [&str; 25] = [
+    "dollar", "dollaroldstyle", "euro", "eur", "eurcr", "eurdig", "eurhv", "eurtm", "pounds", "sterling",
+    "yen", "cent", "centoldstyle", "won", "lira", "naira", "peso", "baht", "dong", "guarani", "florin",
+    "currency", "colonmonetary", "pfund", "shilling"
+];
+
+/// The lowercase command name without its backslash or `{...}` argument, e.g. `\mathcal{A}` -> `mathcal`.
+fn base_name(command: &str) -> String {
+    let name = command.trim_start_matches('\\').to_lowercase();
+    name.split('{').next().unwrap_or_default().to_string()
+}
+
+/// Greek letters, including the upright (`\upalpha`) and variant (`\varphi`) forms.
+fn is_greek(name: &str) -> bool {
+    let up = name.strip_prefix("up").unwrap_or(name);
+    let var = up.strip_prefix("var").unwrap_or(up);
+    [name, up, var].iter().any(|candidate| GREEK_LETTERS.contains(candidate))
+}
+
+/// Everyday words for what a symbol is, so "arrow" or "greek" finds it without knowing the command.
+pub fn aliases(symbol: &Symbol) -> Vec<&'static str> {
+    let command = symbol.command.to_lowercase();
+    let base = base_name(&symbol.command);
+    let mut aliases = Vec::new();
+
+    if is_greek(&base) {
+        aliases.push("greek");
+    }
+    let arrows = ["arrow", "mapsto", "mapsfrom", "harpoon", "leadsto"];
+    if arrows.iter().any(|arrow| command.contains(arrow)) {
+        aliases.push("arrow");
+    }
+    if base.ends_with("int") || base.contains("intclockwise") || base.contains("intctrclockwise") {
+        aliases.push("integral");
+    }
+    if base.starts_with("big") || ["sum", "prod", "coprod"].contains(&base.as_str()) {
+        aliases.push("operator");
+    }
+    if command.starts_with("\\mathbb") || command.starts_with("\\mathds") {
+        aliases.extend(["blackboard", "double-struck"]);
+    }
+    if command.starts_with("\\mathcal") || command.starts_with("\\mathscr") {
+        aliases.push("script");
+    }
+    if command.starts_with("\\mathfrak") {
+        aliases.push("fraktur");
+    }
+    if ZODIAC_SIGNS.iter().any(|sign| base.starts_with(sign)) {
+        aliases.push("zodiac");
+    }
+    if PLANETS.contains(&base.trim_start_matches("astro")) {
+        aliases.push("planet");
+    }
+    let unprefixed = base.trim_start_matches("text").trim_start_matches("math");
+    if CURRENCIES.contains(&unprefixed) {
+        aliases.push("currency");
+    }
+    if symbol.package == "tipa" {
+        aliases.extend(["phonetic", "ipa"]);
+    }
+    if base.contains("eq") || base.contains("sim") || base.contains("approx") || base.contains("cong") {
+        aliases.push("relation");
+    }
+    if base.contains("smiley") || base.contains("frown") {
+        aliases.push("face");
+    }
+
+    aliases
+}
+
+/// Scores `needle` as a subsequence of `haystack`, higher for consecutive runs and earlier starts.
+/// Returns `None` if some character of `needle` doesn't appear in order.
+pub fn fuzzy_score(needle: &str, haystack: &str) -> Option<u32> {
+    let haystack = haystack.to_lowercase();
+    if haystack == needle {
+        return Some(1000);
+    }
+    if let Some(position) = haystack.find(needle) {
+        // Substring matches always beat scattered ones, prefixes most of all
+        let prefix_bonus = if position == 0 { 100 } else { 0 };
+        return Some(500 + prefix_bonus - (position as u32).min(99));
+    }
+
+    let mut score = 0;
+    let mut run = 0;
+    let mut characters = haystack.chars();
+    for wanted in needle.chars() {
+        let mut skipped = false;
+        loop {
+            match characters.next() {
+                Some(c) if c == wanted => break,
+                Some(_) => skipped = true,
+                None => return None
+            }
+        }
+        run = if skipped { 1 } else { run + 1 };
+        score += run;
+    }
+
+    Some(score)
+}
+
+/// Symbols matching every whitespace-separated term of `query` against their command, package or
+/// aliases, best first. An empty query matches everything in catalogue order.
+pub fn search<'a>(catalogue: &'a SymbolCatalogue, query: &str) -> Vec<&'a Symbol> {
+    let terms: Vec<String> = query.split_whitespace()
+        .map(|term| term.trim_start_matches('\\').to_lowercase())
+        .filter(|term| !term.is_empty())
+        .collect();
+
+    let mut matches: Vec<(u32, usize, &Symbol)> = catalogue.iter()
+        .enumerate()
+        .filter_map(|(index, symbol)| {
+            let command = symbol.command.trim_start_matches('\\');
+            let aliases = aliases(symbol);
+            let mut total = 0;
+            for term in &terms {
+                let best = std::iter::once(fuzzy_score(term, command))
+                    .chain(std::iter::once(fuzzy_score(term, &symbol.package).map(|score| score / 2)))
+                    .chain(aliases.iter().map(|alias| fuzzy_score(term, alias).map(|score| score / 2)))
+                    .flatten()
+                    .max()?;
+                total += best;
+            }
+            Some((total, index, symbol))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    matches.into_iter().map(|(_, _, symbol)| symbol).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::search;
+    use crate::symbols::SymbolCatalogue;
+
+    fn commands(query: &str) -> Vec<&'static str> {
+        search(SymbolCatalogue::embedded(), query).into_iter()
+            .map(|symbol| symbol.command.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn exact_commands_come_first() {
+        assert_eq!(commands("\\alpha")[0], "\\alpha");
+        assert_eq!(commands("int")[0], "\\int");
+    }
+
+    #[test]
+    fn prefixes_beat_later_substrings() {
+        let results = commands("alph");
+        let position = |command| results.iter().position(|&result| result == command).unwrap();
+
+        assert_eq!(results[0], "\\alpha");
+        assert!(position("\\alpha") < position("\\upalpha"));
+    }
+
+    #[test]
+    fn aliases_find_symbols_by_what_they_are() {
+        assert!(commands("arrow").contains(&"\\leadsto"));
+        assert!(commands("greek").contains(&"\\alpha"));
+        assert!(commands("blackboard").iter().any(|command| command.starts_with("\\mathbb")));
+    }
+
+    #[test]
+    fn typos_with_missing_letters_still_match() {
+        let results = commands("rightarow");
+
+        assert!(results[..5].contains(&"\\rightarrow"), "{:?}", &results[..5]);
+        assert!(commands("qqqq").is_empty());
+    }
+
+    #[test]
+    fn every_term_must_match() {
+        let results = commands("arrow left");
+
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|command| command.to_lowercase().contains("left")));
+    }
+
+    #[test]
+    fn an_empty_query_keeps_catalogue_order() {
+        let catalogue = SymbolCatalogue::embedded();
+
+        assert_eq!(search(catalogue, "  ").len(), catalogue.len());
+        assert_eq!(search(catalogue, "")[0].id, catalogue.iter().next().unwrap().id);
+    }
+}
@@ -0,0 +1,131 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static SYMBOLS_JSON: &str = include_str!("../../data/symbols.json");
+
+/// One entry of the detexify symbol database, `data/symbols.json`.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Symbol {
+    /// The detexify key, e.g. `latex2e-OT1-_alpha`, which is also the model label.
+    pub id: String,
+    /// The LaTeX source producing the symbol, e.g. `\alpha`.
+    pub command: String,
+    pub mathmode: bool,
+    pub textmode: bool,
+    pub css_class: String,
+    /// The package the symbol needs, e.g. `amssymb`; `latex2e` means none.
+    #[serde(default = "no_package")]
+    pub package: String,
+    /// The font encoding the symbol needs, e.g. `T1`; `None` means the default OT1.
+    #[serde(default)]
+    pub fontenc: Option<String>
+}
+
+// Plain LaTeX entries have no `package` in symbols.json
+fn no_package() -> String {
+    "latex2e".to_string()
+}
+
+impl Symbol {
+    /// The font encoding, `OT1` or `T1`.
+    pub fn encoding(&self) -> &str {
+        self.fontenc.as_deref().unwrap_or("OT1")
+    }
+
+    /// The preamble lines the symbol needs, e.g. `\usepackage{amssymb}`; empty for plain LaTeX.
+    pub fn preamble(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        // T1 glyphs such as \DH don't exist in the default OT1 encoding
+        if let Some(fontenc) = &self.fontenc {
+            lines.push(format!("\\usepackage[{fontenc}]{{fontenc}}"));
+        }
+        if self.package != "latex2e" {
+            lines.push(format!("\\usepackage{{{}}}", self.package));
+        }
+        lines
+    }
+
+    /// The command ready to paste into running text: in `$...$` if it only works in math mode,
+    /// and followed by `{}` if it's a text-mode control word that would swallow the next space.
+    pub fn wrapped(&self) -> String {
+        let ends_in_letter = self.command.chars().last().is_some_and(|c| c.is_ascii_alphabetic());
+        match (self.mathmode, self.textmode) {
+            (true, false) => format!("${}$", self.command),
+            (false, true) if self.command.starts_with('\\') && ends_in_letter => format!("{}{{}}", self.command),
+            _ => self.command.clone()
+        }
+    }
+
+    /// The smallest document that typesets the symbol.
+    pub fn example_document(&self) -> String {
+        let mut lines = vec!["\\documentclass{article}".to_string()];
+        lines.extend(self.preamble());
+        lines.push("\\begin{document}".to_string());
+        lines.push(self.wrapped());
+        lines.push("\\end{document}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Every detexify symbol, indexed by key.
+#[derive(Debug)]
+pub struct SymbolCatalogue {
+    symbols: Vec<Symbol>,
+    by_key: HashMap<String, usize>
+}
+
+impl SymbolCatalogue {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let symbols: Vec<Symbol> = serde_json::from_str(json)?;
+        let mut by_key: HashMap<String, usize> = symbols.iter()
+            .enumerate()
+            .map(|(index, symbol)| (symbol.id.clone(), index))
+            .collect();
+
+        // Some training keys spell `latex2e-OT1-[` as `latex2e-OT1-_[`, the usual key layout
+        for (index, symbol) in symbols.iter().enumerate() {
+            let parts: Vec<&str> = symbol.id.splitn(3, '-').collect();
+            if let [package, encoding, command] = parts[..] && !command.starts_with('_') {
+                by_key.entry(format!("{package}-{encoding}-_{command}")).or_insert(index);
+            }
+        }
+
+        Ok(Self { symbols, by_key })
+    }
+
+    /// The catalogue compiled into the binary from `data/symbols.json`.
+    pub fn embedded() -> &'static SymbolCatalogue {
+        static CATALOGUE: OnceLock<SymbolCatalogue> = OnceLock::new();
+        CATALOGUE.get_or_init(|| Self::from_json(SYMBOLS_JSON).expect("symbols.json should be valid"))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Symbol> {
+        self.by_key.get(key).map(|&index| &self.symbols[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol> {
+        self.symbols.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SymbolCatalogue;
+
+    #[test]
+    fn catalogue_packages_match_keys() {
+        for symbol in SymbolCatalogue::embedded().iter() {
+            let prefix = format!("{}-{}-", symbol.package, symbol.encoding());
+            assert!(symbol.id.starts_with(&prefix), "{} doesn't start with {prefix}", symbol.id);
+        }
+    }
+}
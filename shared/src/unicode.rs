@@ -0,0 +1,285 @@
+/// The Unicode character a LaTeX command produces, and its `unicode-math` command if it has one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnicodeEquivalent {
+    pub character: char,
+    pub unicode_math: Option<String>
+}
+
+impl UnicodeEquivalent {
+    /// The code point in `U+XXXX` notation.
+    pub fn code_point(&self) -> String {
+        format!("U+{:04X}", self.character as u32)
+    }
+}
+
+// (command, character, unicode-math command or "" for text-only symbols)
+static TABLE: &[(&str, char, &str)] = &[
+    // Greek
+    ("\\alpha", 'α', "\\alpha"), ("\\beta", 'β', "\\beta"), ("\\gamma", 'γ', "\\gamma"),
+    ("\\delta", 'δ', "\\delta"), ("\\epsilon", 'ϵ', "\\epsilon"), ("\\varepsilon", 'ε', "\\varepsilon"),
+    ("\\zeta", 'ζ', "\\zeta"), ("\\eta", 'η', "\\eta"), ("\\theta", 'θ', "\\theta"),
+    ("\\vartheta", 'ϑ', "\\vartheta"), ("\\iota", 'ι', "\\iota"), ("\\kappa", 'κ', "\\kappa"),
+    ("\\varkappa", 'ϰ', "\\varkappa"), ("\\lambda", 'λ', "\\lambda"), ("\\mu", 'μ', "\\mu"),
+    ("\\nu", 'ν', "\\nu"), ("\\xi", 'ξ', "\\xi"), ("\\pi", 'π', "\\pi"), ("\\varpi", 'ϖ', "\\varpi"),
+    ("\\rho", 'ρ', "\\rho"), ("\\varrho", 'ϱ', "\\varrho"), ("\\sigma", 'σ', "\\sigma"),
+    ("\\varsigma", 'ς', "\\varsigma"), ("\\tau", 'τ', "\\tau"), ("\\upsilon", 'υ', "\\upsilon"),
+    ("\\phi", 'ϕ', "\\phi"), ("\\varphi", 'φ', "\\varphi"), ("\\chi", 'χ', "\\chi"),
+    ("\\psi", 'ψ', "\\psi"), ("\\omega", 'ω', "\\omega"), ("\\digamma", 'ϝ', "\\digamma"),
+    ("\\Gamma", 'Γ', "\\Gamma"), ("\\Delta", 'Δ', "\\Delta"), ("\\Theta", 'Θ', "\\Theta"),
+    ("\\Lambda", 'Λ', "\\Lambda"), ("\\Xi", 'Ξ', "\\Xi"), ("\\Pi", 'Π', "\\Pi"), ("\\Sigma", 'Σ', "\\Sigma"),
+    ("\\Upsilon", 'Υ', "\\Upsilon"), ("\\Phi", 'Φ', "\\Phi"), ("\\Psi", 'Ψ', "\\Psi"), ("\\Omega", 'Ω', "\\Omega"),
+    // Letter-like
+    ("\\aleph", 'ℵ', "\\aleph"), ("\\hbar", 'ℏ', "\\hbar"), ("\\hslash", 'ℏ', "\\hslash"),
+    ("\\ell", 'ℓ', "\\ell"), ("\\wp", '℘', "\\wp"), ("\\Re", 'ℜ', "\\Re"), ("\\Im", 'ℑ', "\\Im"),
+    ("\\partial", '∂', "\\partial"), ("\\infty", '∞', "\\infty"), ("\\nabla", '∇', "\\nabla"),
+    ("\\imath", 'ı', "\\imath"), ("\\jmath", 'ȷ', "\\jmath"), ("\\eth", 'ð', "\\eth"),
+    ("\\mho", '℧', "\\mho"), ("\\Finv", 'Ⅎ', "\\Finv"), ("\\Game", '⅁', "\\Game"), ("\\Bbbk", '𝕜', "\\Bbbk"),
+    ("\\emptyset", '∅', "\\emptyset"), ("\\varnothing", '∅', "\\varnothing"),
+    ("\\complement", '∁', "\\complement"), ("\\backprime", '‵', "\\backprime"), ("\\prime", '′', "\\prime"),
+    // Binary operators
+    ("\\pm", '±', "\\pm"), ("\\mp", '∓', "\\mp"), ("\\times", '×', "\\times"), ("\\div", '÷', "\\div"),
+    ("\\cdot", '⋅', "\\cdot"), ("\\ast", '∗', "\\ast"), ("\\star", '⋆', "\\star"), ("\\circ", '∘', "\\circ"),
+    ("\\bullet", '∙', "\\vysmblkcircle"), ("\\cap", '∩', "\\cap"), ("\\cup", '∪', "\\cup"),
+    ("\\uplus", '⊎', "\\uplus"), ("\\sqcap", '⊓', "\\sqcap"), ("\\sqcup", '⊔', "\\sqcup"),
+    ("\\vee", '∨', "\\vee"), ("\\wedge", '∧', "\\wedge"), ("\\setminus", '∖', "\\setminus"),
+    ("\\wr", '≀', "\\wr"), ("\\diamond", '⋄', "\\diamond"), ("\\oplus", '⊕', "\\oplus"),
+    ("\\ominus", '⊖', "\\ominus"), ("\\otimes", '⊗', "\\otimes"), ("\\oslash", '⊘', "\\oslash"),
+    ("\\odot", '⊙', "\\odot"), ("\\dagger", '†', "\\dagger"), ("\\ddagger", '‡', "\\ddagger"),
+    ("\\amalg", '⨿', "\\amalg"), ("\\bigcirc", '○', "\\bigcirc"), ("\\dotplus", '∔', "\\dotplus"),
+    ("\\ltimes", '⋉', "\\ltimes"), ("\\rtimes", '⋊', "\\rtimes"), ("\\boxplus", '⊞', "\\boxplus"),
+    ("\\boxminus", '⊟', "\\boxminus"), ("\\boxtimes", '⊠', "\\boxtimes"), ("\\boxdot", '⊡', "\\boxdot"),
+    ("\\intercal", '⊺', "\\intercal"), ("\\veebar", '⊻', "\\veebar"), ("\\barwedge", '⊼', "\\barwedge"),
+    ("\\curlyvee", '⋎', "\\curlyvee"), ("\\curlywedge", '⋏', "\\curlywedge"), ("\\Cap", '⋒', "\\Cap"),
+    ("\\Cup", '⋓', "\\Cup"), ("\\divideontimes", '⋇', "\\divideontimes"),
+    ("\\leftthreetimes", '⋋', "\\leftthreetimes"), ("\\rightthreetimes", '⋌', "\\rightthreetimes"),
+    // Large operators
+    ("\\sum", '∑', "\\sum"), ("\\prod", '∏', "\\prod"), ("\\coprod", '∐', "\\coprod"), ("\\int", '∫', "\\int"),
+    ("\\iint", '∬', "\\iint"), ("\\iiint", '∭', "\\iiint"), ("\\iiiint", '⨌', "\\iiiint"), ("\\oint", '∮', "\\oint"),
+    ("\\oiint", '∯', "\\oiint"), ("\\fint", '⨏', "\\fint"), ("\\sqint", '⨖', "\\sqint"),
+    ("\\ointclockwise", '∲', "\\varointclockwise"), ("\\ointctrclockwise", '∳', "\\ointctrclockwise"),
+    ("\\bigcap", '⋂', "\\bigcap"), ("\\bigcup", '⋃', "\\bigcup"), ("\\bigvee", '⋁', "\\bigvee"),
+    ("\\bigwedge", '⋀', "\\bigwedge"), ("\\bigoplus", '⨁', "\\bigoplus"), ("\\bigotimes", '⨂', "\\bigotimes"),
+    ("\\bigodot", '⨀', "\\bigodot"), ("\\biguplus", '⨄', "\\biguplus"), ("\\bigsqcup", '⨆', "\\bigsqcup"),
+    // Relations
+    ("\\leq", '≤', "\\leq"), ("\\geq", '≥', "\\geq"), ("\\neq", '≠', "\\neq"), ("\\equiv", '≡', "\\equiv"),
+    ("\\approx", '≈', "\\approx"), ("\\sim", '∼', "\\sim"), ("\\simeq", '≃', "\\simeq"), ("\\cong", '≅', "\\cong"),
+    ("\\asymp", '≍', "\\asymp"), ("\\propto", '∝', "\\propto"), ("\\doteq", '≐', "\\doteq"),
+    ("\\ll", '≪', "\\ll"), ("\\gg", '≫', "\\gg"), ("\\lll", '⋘', "\\lll"), ("\\ggg", '⋙', "\\ggg"),
+    ("\\prec", '≺', "\\prec"), ("\\succ", '≻', "\\succ"), ("\\preceq", '⪯', "\\preceq"), ("\\succeq", '⪰', "\\succeq"),
+    ("\\subset", '⊂', "\\subset"), ("\\supset", '⊃', "\\supset"), ("\\subseteq", '⊆', "\\subseteq"),
+    ("\\supseteq", '⊇', "\\supseteq"), ("\\subsetneq", '⊊', "\\subsetneq"), ("\\supsetneq", '⊋', "\\supsetneq"),
+    ("\\Subset", '⋐', "\\Subset"), ("\\Supset", '⋑', "\\Supset"), ("\\sqsubset", '⊏', "\\sqsubset"),
+    ("\\sqsupset", '⊐', "\\sqsupset"), ("\\sqsubseteq", '⊑', "\\sqsubseteq"), ("\\sqsupseteq", '⊒', "\\sqsupseteq"),
+    ("\\in", '∈', "\\in"), ("\\ni", '∋', "\\ni"), ("\\notin", '∉', "\\notin"), ("\\mid", '∣', "\\mid"),
+    ("\\nmid", '∤', "\\nmid"), ("\\parallel", '∥', "\\parallel"), ("\\nparallel", '∦', "\\nparallel"),
+    ("\\perp", '⟂', "\\perp"), ("\\vdash", '⊢', "\\vdash"), ("\\dashv", '⊣', "\\dashv"), ("\\models", '⊨', "\\models"),
+    ("\\vDash", '⊨', "\\vDash"), ("\\Vdash", '⊩', "\\Vdash"), ("\\Vvdash", '⊪', "\\Vvdash"),
+    ("\\nvdash", '⊬', "\\nvdash"), ("\\nvDash", '⊭', "\\nvDash"), ("\\nVDash", '⊯', "\\nVDash"),
+    ("\\bowtie", '⋈', "\\bowtie"), ("\\smile", '⌣', "\\smile"), ("\\frown", '⌢', "\\frown"),
+    ("\\leqq", '≦', "\\leqq"), ("\\geqq", '≧', "\\geqq"), ("\\leqslant", '⩽', "\\leqslant"),
+    ("\\geqslant", '⩾', "\\geqslant"), ("\\lesssim", '≲', "\\lesssim"), ("\\gtrsim", '≳', "\\gtrsim"),
+    ("\\lessgtr", '≶', "\\lessgtr"), ("\\gtrless", '≷', "\\gtrless"), ("\\lessdot", '⋖', "\\lessdot"),
+    ("\\gtrdot", '⋗', "\\gtrdot"), ("\\approxeq", '≊', "\\approxeq"), ("\\backsim", '∽', "\\backsim"),
+    ("\\backsimeq", '⋍', "\\backsimeq"), ("\\triangleq", '≜', "\\triangleq"), ("\\circeq", '≗', "\\circeq"),
+    ("\\bumpeq", '≏', "\\bumpeq"), ("\\Bumpeq", '≎', "\\Bumpeq"), ("\\doteqdot", '≑', "\\Doteq"),
+    ("\\risingdotseq", '≓', "\\risingdotseq"), ("\\fallingdotseq", '≒', "\\fallingdotseq"),
+    ("\\therefore", '∴', "\\therefore"), ("\\because", '∵', "\\because"), ("\\between", '≬', "\\between"),
+    ("\\pitchfork", '⋔', "\\pitchfork"), ("\\multimap", '⊸', "\\multimap"), ("\\ncong", '≇', "\\ncong"),
+    ("\\nsim", '≁', "\\nsim"), ("\\nleq", '≰', "\\nleq"), ("\\ngeq", '≱', "\\ngeq"), ("\\nless", '≮', "\\nless"),
+    ("\\ngtr", '≯', "\\ngtr"), ("\\nprec", '⊀', "\\nprec"), ("\\nsucc", '⊁', "\\nsucc"),
+    ("\\nsubseteq", '⊈', "\\nsubseteq"), ("\\nsupseteq", '⊉', "\\nsupseteq"), ("\\nexists", '∄', "\\nexists"),
+    ("\\vartriangleleft", '⊲', "\\vartriangleleft"), ("\\vartriangleright", '⊳', "\\vartriangleright"),
+    ("\\trianglelefteq", '⊴', "\\trianglelefteq"), ("\\trianglerighteq", '⊵', "\\trianglerighteq"),
+    // Arrows
+    ("\\leftarrow", '←', "\\leftarrow"), ("\\rightarrow", '→', "\\rightarrow"), ("\\uparrow", '↑', "\\uparrow"),
+    ("\\downarrow", '↓', "\\downarrow"), ("\\leftrightarrow", '↔', "\\leftrightarrow"),
+    ("\\updownarrow", '↕', "\\updownarrow"), ("\\Leftarrow", '⇐', "\\Leftarrow"), ("\\Rightarrow", '⇒', "\\Rightarrow"),
+    ("\\Uparrow", '⇑', "\\Uparrow"), ("\\Downarrow", '⇓', "\\Downarrow"), ("\\Leftrightarrow", '⇔', "\\Leftrightarrow"),
+    ("\\Updownarrow", '⇕', "\\Updownarrow"), ("\\longleftarrow", '⟵', "\\longleftarrow"),
+    ("\\longrightarrow", '⟶', "\\longrightarrow"), ("\\longleftrightarrow", '⟷', "\\longleftrightarrow"),
+    ("\\Longleftarrow", '⟸', "\\Longleftarrow"), ("\\Longrightarrow", '⟹', "\\Longrightarrow"),
+    ("\\Longleftrightarrow", '⟺', "\\Longleftrightarrow"), ("\\mapsto", '↦', "\\mapsto"),
+    ("\\longmapsto", '⟼', "\\longmapsto"), ("\\hookleftarrow", '↩', "\\hookleftarrow"),
+    ("\\hookrightarrow", '↪', "\\hookrightarrow"), ("\\nearrow", '↗', "\\nearrow"), ("\\searrow", '↘', "\\searrow"),
+    ("\\swarrow", '↙', "\\swarrow"), ("\\nwarrow", '↖', "\\nwarrow"), ("\\leftharpoonup", '↼', "\\leftharpoonup"),
+    ("\\leftharpoondown", '↽', "\\leftharpoondown"), ("\\rightharpoonup", '⇀', "\\rightharpoonup"),
+    ("\\rightharpoondown", '⇁', "\\rightharpoondown"), ("\\rightleftharpoons", '⇌', "\\rightleftharpoons"),
+    ("\\leftrightharpoons", '⇋', "\\leftrightharpoons"), ("\\leftleftarrows", '⇇', "\\leftleftarrows"),
+    ("\\rightrightarrows", '⇉', "\\rightrightarrows"), ("\\leftrightarrows", '⇆', "\\leftrightarrows"),
+    ("\\rightleftarrows", '⇄', "\\rightleftarrows"), ("\\upuparrows", '⇈', "\\upuparrows"),
+    ("\\downdownarrows", '⇊', "\\downdownarrows"), ("\\Lleftarrow", '⇚', "\\Lleftarrow"),
+    ("\\Rrightarrow", '⇛', "\\Rrightarrow"), ("\\twoheadleftarrow", '↞', "\\twoheadleftarrow"),
+    ("\\twoheadrightarrow", '↠', "\\twoheadrightarrow"), ("\\leftarrowtail", '↢', "\\leftarrowtail"),
+    ("\\rightarrowtail", '↣', "\\rightarrowtail"), ("\\looparrowleft", '↫', "\\looparrowleft"),
+    ("\\looparrowright", '↬', "\\looparrowright"), ("\\curvearrowleft", '↶', "\\curvearrowleft"),
+    ("\\curvearrowright", '↷', "\\curvearrowright"), ("\\circlearrowleft", '↺', "\\acwopencirclearrow"),
+    ("\\circlearrowright", '↻', "\\cwopencirclearrow"), ("\\Lsh", '↰', "\\Lsh"), ("\\Rsh", '↱', "\\Rsh"),
+    ("\\nleftarrow", '↚', "\\nleftarrow"), ("\\nrightarrow", '↛', "\\nrightarrow"),
+    ("\\nLeftarrow", '⇍', "\\nLeftarrow"), ("\\nRightarrow", '⇏', "\\nRightarrow"),
+    ("\\nleftrightarrow", '↮', "\\nleftrightarrow"), ("\\nLeftrightarrow", '⇎', "\\nLeftrightarrow"),
+    ("\\leadsto", '⇝', "\\leadsto"), ("\\rightsquigarrow", '⇝', "\\rightsquigarrow"),
+    ("\\leftrightsquigarrow", '↭', "\\leftrightsquigarrow"),
+    // Miscellaneous math
+    ("\\forall", '∀', "\\forall"), ("\\exists", '∃', "\\exists"), ("\\neg", '¬', "\\neg"), ("\\top", '⊤', "\\top"),
+    ("\\bot", '⊥', "\\bot"), ("\\angle", '∠', "\\angle"), ("\\measuredangle", '∡', "\\measuredangle"),
+    ("\\sphericalangle", '∢', "\\sphericalangle"), ("\\surd", '√', "\\surd"), ("\\triangle", '△', "\\triangle"),
+    ("\\flat", '♭', "\\flat"), ("\\natural", '♮', "\\natural"), ("\\sharp", '♯', "\\sharp"),
+    ("\\clubsuit", '♣', "\\clubsuit"), ("\\diamondsuit", '♢', "\\diamondsuit"), ("\\heartsuit", '♡', "\\heartsuit"),
+    ("\\spadesuit", '♠', "\\spadesuit"), ("\\ldots", '…', "\\unicodeellipsis"), ("\\cdots", '⋯', "\\cdots"),
+    ("\\vdots", '⋮', "\\vdots"), ("\\ddots", '⋱', "\\ddots"), ("\\iddots", '⋰', "\\adots"),
+    ("\\langle", '⟨', "\\langle"), ("\\rangle", '⟩', "\\rangle"), ("\\lceil", '⌈', "\\lceil"),
+    ("\\rceil", '⌉', "\\rceil"), ("\\lfloor", '⌊', "\\lfloor"), ("\\rfloor", '⌋', "\\rfloor"),
+    ("\\llbracket", '⟦', "\\lBrack"), ("\\rrbracket", '⟧', "\\rBrack"), ("\\square", '□', "\\square"),
+    ("\\blacksquare", '■', "\\blacksquare"), ("\\lozenge", '◊', "\\lozenge"), ("\\blacklozenge", '⧫', "\\blacklozenge"),
+    ("\\bigstar", '★', "\\bigstar"), ("\\checkmark", '✓', "\\checkmark"), ("\\maltese", '✠', "\\maltese"),
+    ("\\circledS", 'Ⓢ', "\\circledS"), ("\\circledR", '®', "\\circledR"), ("\\diagup", '╱', "\\diagup"),
+    ("\\diagdown", '╲', "\\diagdown"), ("\\blacktriangle", '▴', "\\blacktriangle"),
+    ("\\blacktriangledown", '▾', "\\blacktriangledown"), ("\\blacktriangleleft", '◂', "\\blacktriangleleft"),
+    ("\\blacktriangleright", '▸', "\\blacktriangleright"), ("\\backslash", '\\', "\\backslash"),
+    // Text symbols
+    ("\\textdollar", '$', ""), ("\\textsterling", '£', ""), ("\\pounds", '£', "\\mathsterling"),
+    ("\\texteuro", '€', ""), ("\\textyen", '¥', ""), ("\\textcent", '¢', ""), ("\\textcopyright", '©', ""),
+    ("\\copyright", '©', ""), ("\\textregistered", '®', ""), ("\\texttrademark", '™', ""),
+    ("\\textparagraph", '¶', ""), ("\\P", '¶', ""), ("\\textsection", '§', ""), ("\\S", '§', ""),
+    ("\\textdagger", '†', ""), ("\\textdaggerdbl", '‡', ""), ("\\textbullet", '•', ""),
+    ("\\textperiodcentered", '·', ""), ("\\textellipsis", '…', ""), ("\\textemdash", '—', ""),
+    ("\\textendash", '–', ""), ("\\textexclamdown", '¡', ""), ("\\textquestiondown", '¿', ""),
+    ("\\textordfeminine", 'ª', ""), ("\\textordmasculine", 'º', ""), ("\\textdegree", '°', ""),
+    ("\\textonehalf", '½', ""), ("\\textonequarter", '¼', ""),
+    ("\\textthreequarters", '¾', ""), ("\\textperthousand", '‰', ""), ("\\textnumero", '№', ""),
+    ("\\textinterrobang", '‽', ""), ("\\textreferencemark", '※', ""), ("\\textmusicalnote", '♪', ""),
+    ("\\textbigcircle", '◯', ""), ("\\textquotedblleft", '“', ""), ("\\textquotedblright", '”', ""),
+    ("\\textquoteleft", '‘', ""), ("\\textquoteright", '’', ""), ("\\guillemotleft", '«', ""),
+    ("\\guillemotright", '»', ""), ("\\guilsinglleft", '‹', ""), ("\\guilsinglright", '›', ""),
+    ("\\quotedblbase", '„', ""), ("\\quotesinglbase", '‚', ""), ("\\textquotedbl", '"', ""),
+    ("\\AA", 'Å', ""), ("\\aa", 'å', ""), ("\\AE", 'Æ', ""), ("\\ae", 'æ', ""), ("\\OE", 'Œ', ""), ("\\oe", 'œ', ""),
+    ("\\O", 'Ø', ""), ("\\o", 'ø', ""), ("\\L", 'Ł', ""), ("\\l", 'ł', ""), ("\\ss", 'ß', ""),
+    ("\\DH", 'Ð', ""), ("\\dh", 'ð', ""), ("\\DJ", 'Đ', ""), ("\\dj", 'đ', ""), ("\\NG", 'Ŋ', ""), ("\\ng", 'ŋ', ""),
+    ("\\TH", 'Þ', ""), ("\\th", 'þ', ""),
+    // gensymb and wasysym
+    ("\\degree", '°', ""), ("\\celsius", '℃', ""), ("\\ohm", 'Ω', ""), ("\\permil", '‰', ""),
+    ("\\smiley", '☺', ""), ("\\frownie", '☹', ""), ("\\blacksmiley", '☻', ""), ("\\sun", '☼', ""),
+    ("\\female", '♀', ""), ("\\male", '♂', ""), ("\\earth", '♁', ""), ("\\mercury", '☿', ""),
+    ("\\venus", '♀', ""), ("\\mars", '♂', ""), ("\\jupiter", '♃', ""), ("\\saturn", '♄', ""),
+    ("\\uranus", '♅', ""), ("\\neptune", '♆', ""), ("\\pluto", '♇', ""), ("\\aries", '♈', ""),
+    ("\\taurus", '♉', ""), ("\\gemini", '♊', ""), ("\\cancer", '♋', ""), ("\\leo", '♌', ""), ("\\virgo", '♍', ""),
+    ("\\libra", '♎', ""), ("\\scorpio", '♏', ""), ("\\sagittarius", '♐', ""), ("\\capricornus", '♑', ""),
+    ("\\aquarius", '♒', ""), ("\\pisces", '♓', ""), ("\\phone", '☎', ""), ("\\diameter", '⌀', "\\diameter"),
+    ("\\skull", '☠', "")
+];
+
+const GREEK_NAMES: [(&str, char); 24] = [
+    ("alpha", 'α'), ("beta", 'β'), ("gamma", 'γ'), ("delta", 'δ'), ("epsilon", 'ε'), ("zeta", 'ζ'),
+    ("eta", 'η'), ("theta", 'θ'), ("iota", 'ι'), ("kappa", 'κ'), ("lambda", 'λ'), ("mu", 'μ'),
+    ("nu", 'ν'), ("xi", 'ξ'), ("pi", 'π'), ("rho", 'ρ'), ("sigma", 'σ'), ("tau", 'τ'),
+    ("upsilon", 'υ'), ("phi", 'φ'), ("chi", 'χ'), ("psi", 'ψ'), ("omega", 'ω'), ("omicron", 'ο')
+];
+
+fn offset(base: u32, letter: char, first: char) -> Option<char> {
+    char::from_u32(base + (letter as u32 - first as u32))
+}
+
+/// `\mathbb{R}`, `\mathcal{A}`, `\mathfrak{g}` and friends, which live in the
+/// Mathematical Alphanumeric Symbols block apart from a few older letter-like symbols.
+fn alphabet(command: &str) -> Option<UnicodeEquivalent> {
+    let (style, rest) = command.strip_prefix('\\')?.split_once('{')?;
+    let mut letters = rest.strip_suffix('}')?.chars();
+    let letter = letters.next()?;
+    if letters.next().is_some() {
+        return None;
+    }
+
+    let (character, prefix) = match (style, letter) {
+        ("mathbb" | "mathds", 'C') => ('ℂ', "Bbb"),
+        ("mathbb" | "mathds", 'H') => ('ℍ', "Bbb"),
+        ("mathbb" | "mathds", 'N') => ('ℕ', "Bbb"),
+        ("mathbb" | "mathds", 'P') => ('ℙ', "Bbb"),
+        ("mathbb" | "mathds", 'Q') => ('ℚ', "Bbb"),
+        ("mathbb" | "mathds", 'R') => ('ℝ', "Bbb"),
+        ("mathbb" | "mathds", 'Z') => ('ℤ', "Bbb"),
+        ("mathbb" | "mathds", 'A'..='Z') => (offset(0x1D538, letter, 'A')?, "Bbb"),
+        ("mathbb" | "mathds", 'a'..='z') => (offset(0x1D552, letter, 'a')?, "Bbb"),
+        ("mathbb" | "mathds", '1') => return Some(UnicodeEquivalent { character: '𝟙', unicode_math: Some("\\Bbbone".to_string()) }),
+        ("mathcal" | "mathscr", 'B') => ('ℬ', "mscr"),
+        ("mathcal" | "mathscr", 'E') => ('ℰ', "mscr"),
+        ("mathcal" | "mathscr", 'F') => ('ℱ', "mscr"),
+        ("mathcal" | "mathscr", 'H') => ('ℋ', "mscr"),
+        ("mathcal" | "mathscr", 'I') => ('ℐ', "mscr"),
+        ("mathcal" | "mathscr", 'L') => ('ℒ', "mscr"),
+        ("mathcal" | "mathscr", 'M') => ('ℳ', "mscr"),
+        ("mathcal" | "mathscr", 'R') => ('ℛ', "mscr"),
+        ("mathcal" | "mathscr", 'A'..='Z') => (offset(0x1D49C, letter, 'A')?, "mscr"),
+        ("mathfrak", 'C') => ('ℭ', "mfrak"),
+        ("mathfrak", 'H') => ('ℌ', "mfrak"),
+        ("mathfrak", 'I') => ('ℑ', "mfrak"),
+        ("mathfrak", 'R') => ('ℜ', "mfrak"),
+        ("mathfrak", 'Z') => ('ℨ', "mfrak"),
+        ("mathfrak", 'A'..='Z') => (offset(0x1D504, letter, 'A')?, "mfrak"),
+        ("mathfrak", 'a'..='z') => (offset(0x1D51E, letter, 'a')?, "mfrak"),
+        _ => return None
+    };
+
+    Some(UnicodeEquivalent { character, unicode_math: Some(format!("\\{prefix}{letter}")) })
+}
+
+/// Upright Greek from `upgreek`, e.g. `\upalpha` and `\Updelta`.
+fn upright_greek(command: &str) -> Option<UnicodeEquivalent> {
+    let name = command.strip_prefix("\\up").or_else(|| command.strip_prefix("\\Up"))?;
+    let upper = command.starts_with("\\Up");
+    let name = name.strip_prefix("var").unwrap_or(name);
+    let &(_, character) = GREEK_NAMES.iter().find(|(greek, _)| *greek == name)?;
+    let character = if upper { character.to_uppercase().next()? } else { character };
+
+    Some(UnicodeEquivalent { character, unicode_math: None })
+}
+
+/// The Unicode equivalent of `command`, where one exists.
+pub fn unicode_equivalent(command: &str) -> Option<UnicodeEquivalent> {
+    if let Some(&(_, character, unicode_math)) = TABLE.iter().find(|(name, _, _)| *name == command) {
+        let unicode_math = (!unicode_math.is_empty()).then(|| unicode_math.to_string());
+        return Some(UnicodeEquivalent { character, unicode_math });
+    }
+
+    alphabet(command).or_else(|| upright_greek(command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unicode_equivalent, TABLE};
+    use crate::symbols::SymbolCatalogue;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn every_table_command_is_in_the_catalogue() {
+        let commands: HashSet<&str> = SymbolCatalogue::embedded().iter()
+            .map(|symbol| symbol.command.as_str())
+            .collect();
+        let unknown: Vec<&str> = TABLE.iter()
+            .map(|&(command, _, _)| command)
+            .filter(|command| !commands.contains(command))
+            .collect();
+
+        assert!(unknown.is_empty(), "Commands missing from symbols.json: {unknown:?}");
+    }
+
+    #[test]
+    fn unicode_math_names_round_trip() {
+        let mut characters: HashMap<&str, char> = HashMap::new();
+        for &(command, character, unicode_math) in TABLE.iter().filter(|(_, _, unicode_math)| !unicode_math.is_empty()) {
+            // Each name stands for one character, the same one if the name is also a LaTeX command
+            let previous = characters.insert(unicode_math, character);
+            assert!(previous.is_none_or(|previous| previous == character), "{unicode_math} names two characters");
+            if let Some(equivalent) = unicode_equivalent(unicode_math) {
+                assert_eq!(equivalent.character, character, "{command} and {unicode_math} differ");
+            }
+        }
+    }
+
+    #[test]
+    fn hbar_keeps_its_name() {
+        let hbar = unicode_equivalent("\\hbar").unwrap();
+
+        assert_eq!(hbar.code_point(), "U+210F");
+        assert_eq!(hbar.unicode_math.as_deref(), Some("\\hbar"));
+    }
+}
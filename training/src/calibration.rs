@@ -0,0 +1,102 @@
+use crate::data::{DetexifyBatch, DetexifyBatcher};
+use crate::infer::load_model;
+use crate::training::TrainingConfig;
+use burn::config::Config;
+use burn::data::dataloader::batcher::Batcher;
+use burn::prelude::Backend;
+use burn::Tensor;
+use shared::calibration::{expected_calibration_error, negative_log_likelihood, CalibrationConfig};
+use shared::item::DetexifyItem;
+use shared::model::Model;
+
+const ECE_BINS: usize = 15;
+const SEARCH_ITERATIONS: usize = 50;
+
+/// Runs the model over `items` and returns the stacked logits and their targets.
+pub fn collect_logits<B: Backend>(model: &Model<B>, device: &B::Device, items: Vec<DetexifyItem>, batch_size: usize) -> (Tensor<B, 2>, Vec<usize>) {
+    let batcher = DetexifyBatcher::default();
+    let targets = items.iter().map(|item| item.label as usize).collect();
+
+    let logits = items
+        .chunks(batch_size)
+        .map(|chunk| {
+            let batch: DetexifyBatch<B> = batcher.batch(chunk.to_vec(), device);
+            model.forward(batch.images)
+        })
+        .collect();
+
+    (Tensor::cat(logits, 0), targets)
+}
+
+/// Top-1 confidence and correctness of every row, for ECE reporting.
+pub fn confidences<B: Backend>(logits: Tensor<B, 2>, targets: &[usize], calibration: &CalibrationConfig) -> (Vec<f32>, Vec<bool>) {
+    let (values, indices) = calibration.probabilities(logits, 1).max_dim_with_indices(1);
+    let values = values.into_data().convert::<f32>().to_vec::<f32>().unwrap();
+    let indices = indices.into_data().convert::<i64>().to_vec::<i64>().unwrap();
+
+    let correct = indices.iter()
+        .zip(targets)
+        .map(|(&predicted, &target)| predicted as usize == target)
+        .collect();
+
+    (values, correct)
+}
+
+/// Fits the softmax temperature on the validation split and writes `calibration.json`.
+pub fn calibrate<B: Backend>(artifact_dir: &str, device: B::Device, items: Vec<DetexifyItem>) -> CalibrationConfig {
+    let config = TrainingConfig::load(format!("{artifact_dir}/config.json"))
+        .expect("Config should exist for the model; run train first");
+    let model = load_model::<B>(artifact_dir, &device);
+
+    let (logits, targets) = collect_logits(&model, &device, items, config.batch_size);
+
+    // NLL is convex in 1 / temperature, so a golden-section search is enough
+    let nll = |inverse_temperature: f64| negative_log_likelihood(logits.clone(), &targets, 1.0 / inverse_temperature);
+    let ratio = (5.0f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (0.05f64, 20.0f64);
+    for _ in 0..SEARCH_ITERATIONS {
+        let left = high - ratio * (high - low);
+        let right = low + ratio * (high - low);
+        if nll(left) < nll(right) {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+    let temperature = 2.0 / (low + high);
+
+    let uncalibrated = CalibrationConfig::new();
+    let calibration = CalibrationConfig::new()
+        .with_temperature(temperature)
+        .with_rejection_threshold(config.rejection_threshold);
+
+    let (before_confidences, before_correct) = confidences(logits.clone(), &targets, &uncalibrated);
+    let (after_confidences, after_correct) = confidences(logits.clone(), &targets, &calibration);
+
+    println!("Fitted temperature: {temperature:.4}");
+    println!(
+        "NLL: {:.4} -> {:.4}",
+        negative_log_likelihood(logits.clone(), &targets, 1.0),
+        negative_log_likelihood(logits, &targets, temperature)
+    );
+    println!(
+        "ECE ({ECE_BINS} bins): {:.4} -> {:.4}",
+        expected_calibration_error(&before_confidences, &before_correct, ECE_BINS),
+        expected_calibration_error(&after_confidences, &after_correct, ECE_BINS)
+    );
+
+    let rejected = after_confidences.iter()
+        .filter(|&&confidence| !calibration.is_recognised(confidence as f64))
+        .count();
+    println!(
+        "Rejected at threshold {:.2}: {rejected}/{}",
+        calibration.rejection_threshold,
+        after_confidences.len()
+    );
+
+    calibration
+        .save(format!("{artifact_dir}/calibration.json"))
+        .expect("Calibration should be saved successfully!");
+
+    calibration
+}
@@ -0,0 +1,115 @@
+use crate::calibration::calibrate;
+use crate::dataset::DetexifyDataset;
+use crate::finetune::{accuracy, fit, mix_with_replay, relabel, FineTuneConfig};
+use crate::infer::load_model;
+use crate::prototypes::{build_prototypes, collect_embeddings};
+use crate::training::TrainingConfig;
+use burn::config::Config;
+use burn::data::dataset::{Dataset, InMemDataset};
+use burn::module::AutodiffModule;
+use burn::prelude::TensorData;
+use burn::tensor::backend::AutodiffBackend;
+use burn::Tensor;
+use shared::item::DetexifyItem;
+use shared::labels::Labels;
+use shared::prototypes::mean_prototype;
+
+/// Grows the checkpoint in `artifact_dir` to cover every key in `keys`, appending the keys it
+/// doesn't know yet to its label list. New `linear2` rows start from the class-mean embedding of
+/// their training samples, then the model is fine-tuned on the new classes mixed with old data
+/// and its calibration and prototypes are rebuilt.
+pub fn expand_classes<B: AutodiffBackend>(
+    artifact_dir: &str,
+    output_dir: &str,
+    config: FineTuneConfig,
+    device: B::Device,
+    keys: &[String],
+    dataset_train: DetexifyDataset,
+    dataset_eval: DetexifyDataset
+) {
+    let training_config = TrainingConfig::load(format!("{artifact_dir}/config.json"))
+        .expect("Config should exist for the model; run train first");
+    let labels = Labels::load(format!("{artifact_dir}/labels.json"))
+        .expect("Labels should exist for the model; run train first");
+
+    let new_keys: Vec<String> = keys.iter()
+        .filter(|key| labels.index_of(key).is_none())
+        .cloned()
+        .collect();
+    if new_keys.is_empty() {
+        println!("No new classes to add; the model already covers every key");
+        return;
+    }
+    println!("Adding {} classes: {:?}", new_keys.len(), new_keys);
+
+    let old_count = labels.len();
+    let expanded = Labels::new(labels.keys.iter().chain(&new_keys).cloned().collect());
+
+    let train_items = relabel(dataset_train.dataset.iter(), keys, &expanded);
+    let test_items = relabel(dataset_eval.dataset.iter(), keys, &expanded);
+    let prototype_items = train_items.clone();
+    let (new_train, old_train): (Vec<DetexifyItem>, Vec<DetexifyItem>) = train_items.into_iter()
+        .partition(|item| item.label as usize >= old_count);
+    let (new_test, old_test): (Vec<DetexifyItem>, Vec<DetexifyItem>) = test_items.iter()
+        .cloned()
+        .partition(|item| item.label as usize >= old_count);
+
+    // A new class's `linear2` row starts from its samples' mean embedding, so it needs some
+    let mut counts = vec![0; new_keys.len()];
+    for item in &new_train {
+        counts[item.label as usize - old_count] += 1;
+    }
+    if let Some(empty) = counts.iter().position(|&count| count == 0) {
+        panic!("{} has no training samples to initialise its class from", new_keys[empty]);
+    }
+
+    B::seed(&device, config.seed);
+    let model = load_model::<B>(artifact_dir, &device);
+
+    // Class-mean embeddings of the new classes, in label order
+    let embeddings = collect_embeddings(&model.valid(), &device, &new_train, config.batch_size);
+    let mut per_class: Vec<Vec<Vec<f32>>> = vec![Vec::new(); new_keys.len()];
+    for (item, embedding) in new_train.iter().zip(embeddings) {
+        per_class[item.label as usize - old_count].push(embedding);
+    }
+    let hidden_size = training_config.model.hidden_size;
+    let values: Vec<f32> = per_class.iter()
+        .flat_map(|embeddings| mean_prototype(embeddings))
+        .collect();
+    let prototypes = Tensor::<B, 2>::from_data(
+        TensorData::new(values, [new_keys.len(), hidden_size]).convert::<B::FloatElem>(),
+        &device
+    );
+    let model = model.expand_classes(prototypes);
+
+    let mut training_config = training_config;
+    training_config.model.num_classes = expanded.len();
+
+    std::fs::create_dir_all(output_dir).unwrap();
+    training_config
+        .save(format!("{output_dir}/config.json"))
+        .expect("Config should be saved successfully!");
+    expanded
+        .save(format!("{output_dir}/labels.json"))
+        .expect("Labels should be saved successfully!");
+    config
+        .save(format!("{output_dir}/finetune.json"))
+        .expect("Fine-tune config should be saved successfully!");
+
+    let before_new = accuracy(&model.valid(), &device, new_test.clone(), config.batch_size);
+    let before_old = accuracy(&model.valid(), &device, old_test.clone(), config.batch_size);
+
+    let old_train = DetexifyDataset { dataset: InMemDataset::new(old_train) };
+    let mixed = mix_with_replay(&new_train, old_train, &config);
+    let model = fit(output_dir, model, &training_config, &config, device.clone(), mixed, test_items.clone());
+
+    let after_new = accuracy(&model, &device, new_test, config.batch_size);
+    let after_old = accuracy(&model, &device, old_test, config.batch_size);
+
+    println!("Accuracy         before   after");
+    println!("New classes      {:>6.2}%  {:>6.2}%", before_new * 100.0, after_new * 100.0);
+    println!("Old classes      {:>6.2}%  {:>6.2}%", before_old * 100.0, after_old * 100.0);
+
+    calibrate::<B::InnerBackend>(output_dir, device.clone(), test_items);
+    build_prototypes::<B::InnerBackend>(output_dir, device, prototype_items, &expanded.keys);
+}
@@ -0,0 +1,207 @@
+use crate::calibration::{calibrate, collect_logits};
+use crate::data::DetexifyBatcher;
+use crate::dataset::DetexifyDataset;
+use crate::infer::load_model;
+use crate::prototypes::build_prototypes;
+use crate::training::TrainingConfig;
+use burn::config::Config;
+use burn::data::dataloader::DataLoaderBuilder;
+use burn::data::dataset::transform::ShuffledDataset;
+use burn::data::dataset::{Dataset, InMemDataset};
+use burn::module::{AutodiffModule, Module};
+use burn::prelude::Backend;
+use burn::record::{BinFileRecorder, FullPrecisionSettings};
+use burn::tensor::backend::AutodiffBackend;
+use burn::train::metric::{AccuracyMetric, LossMetric};
+use burn::train::{LearnerBuilder, LearningStrategy};
+use serde::Deserialize;
+use shared::image_processing::rasterize_strokes;
+use shared::item::DetexifyItem;
+use shared::labels::Labels;
+use shared::model::Model;
+
+/// A drawing the classifier got wrong, with the symbol the user picked instead.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Correction {
+    pub strokes: Vec<Vec<[f32; 3]>>,
+    pub correct_key: String
+}
+
+#[derive(Config, Debug)]
+pub struct FineTuneConfig {
+    #[config(default = 3)]
+    pub num_epochs: usize,
+    #[config(default = 1.0e-5)]
+    pub learning_rate: f64,
+    // Each correction is repeated this many times per epoch
+    #[config(default = 8)]
+    pub correction_repeats: usize,
+    // Original training samples replayed per repeated correction, to avoid forgetting
+    #[config(default = 4)]
+    pub replay_ratio: usize,
+    #[config(default = 64)]
+    pub batch_size: usize,
+    #[config(default = 4)]
+    pub num_workers: usize,
+    #[config(default = 42)]
+    pub seed: u64
+}
+
+/// Maps item labels from indices into `from` to indices into `to`, dropping keys `to` lacks.
+/// Datasets are labelled in sorted database order, checkpoints in their `labels.json` order.
+pub fn relabel(items: impl Iterator<Item = DetexifyItem>, from: &[String], to: &Labels) -> Vec<DetexifyItem> {
+    items
+        .filter_map(|item| {
+            let label = to.index_of(&from[item.label as usize])?;
+            Some(DetexifyItem { label: label as u32, ..item })
+        })
+        .collect()
+}
+
+/// Reads a JSON array (or JSON lines) of corrections, skipping keys the model doesn't know.
+pub fn load_corrections(path: &str, labels: &Labels) -> Vec<DetexifyItem> {
+    let content = std::fs::read_to_string(path).expect("Corrections file should be readable");
+    let corrections: Vec<Correction> = if content.trim_start().starts_with('[') {
+        serde_json::from_str(&content).expect("Corrections should be a JSON array")
+    } else {
+        content.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).expect("Each line should be a correction"))
+            .collect()
+    };
+
+    corrections.iter()
+        .filter_map(|correction| {
+            let Some(label) = labels.index_of(&correction.correct_key) else {
+                println!("Skipping correction with unknown key {}", correction.correct_key);
+                return None;
+            };
+            Some(DetexifyItem {
+                image: rasterize_strokes(&correction.strokes),
+                label: label as u32
+            })
+        })
+        .collect()
+}
+
+pub fn accuracy<B: Backend>(model: &Model<B>, device: &B::Device, items: Vec<DetexifyItem>, batch_size: usize) -> f64 {
+    if items.is_empty() {
+        return 0.0;
+    }
+    let (logits, targets) = collect_logits(model, device, items, batch_size);
+    let predicted = logits.argmax(1).into_data().convert::<i64>().to_vec::<i64>().unwrap();
+
+    let correct = predicted.iter()
+        .zip(&targets)
+        .filter(|&(&predicted, &target)| predicted as usize == target)
+        .count();
+
+    correct as f64 / targets.len() as f64
+}
+
+/// Repeats `items` and pads them with shuffled samples from the original training split.
+pub fn mix_with_replay(items: &[DetexifyItem], dataset_train: DetexifyDataset, config: &FineTuneConfig) -> Vec<DetexifyItem> {
+    let repeated = items.len() * config.correction_repeats;
+    let replay = ShuffledDataset::new(dataset_train.dataset, config.seed);
+
+    items.iter()
+        .cycle()
+        .take(repeated)
+        .cloned()
+        .chain(replay.iter().take(repeated * config.replay_ratio))
+        .collect()
+}
+
+/// Trains an already-loaded `model` at the fine-tuning learning rate and saves it to `output_dir`.
+pub fn fit<B: AutodiffBackend>(
+    output_dir: &str,
+    model: Model<B>,
+    training_config: &TrainingConfig,
+    config: &FineTuneConfig,
+    device: B::Device,
+    train_items: Vec<DetexifyItem>,
+    test_items: Vec<DetexifyItem>
+) -> Model<B::InnerBackend> {
+    let batcher = DetexifyBatcher::default();
+
+    let dataloader_train = DataLoaderBuilder::new(batcher.clone())
+        .batch_size(config.batch_size)
+        .shuffle(config.seed)
+        .num_workers(config.num_workers)
+        .build(InMemDataset::new(train_items));
+
+    let dataloader_test = DataLoaderBuilder::new(batcher)
+        .batch_size(config.batch_size)
+        .num_workers(config.num_workers)
+        .build(InMemDataset::new(test_items));
+
+    let learner = LearnerBuilder::new(output_dir)
+        .metric_train_numeric(AccuracyMetric::new())
+        .metric_valid_numeric(AccuracyMetric::new())
+        .metric_train_numeric(LossMetric::new())
+        .metric_valid_numeric(LossMetric::new())
+        .learning_strategy(LearningStrategy::SingleDevice(device))
+        .num_epochs(config.num_epochs)
+        .build(
+            model,
+            training_config.optimizer.init(),
+            config.learning_rate
+        );
+
+    let result = learner.fit(dataloader_train, dataloader_test);
+
+    result.model.clone().save_file(format!("{output_dir}/model"), &BinFileRecorder::<FullPrecisionSettings>::default())
+        .expect("Fine-tuned model should be saved successfully!");
+
+    result.model
+}
+
+/// Fine-tunes the checkpoint in `artifact_dir` on `corrections` mixed with replayed training data,
+/// writing the result, its calibration and prototypes to `output_dir` and reporting accuracy
+/// before and after. Every item must be labelled in the checkpoint's `labels.json` order; see `relabel`.
+pub fn finetune<B: AutodiffBackend>(
+    artifact_dir: &str,
+    output_dir: &str,
+    config: FineTuneConfig,
+    device: B::Device,
+    corrections: Vec<DetexifyItem>,
+    dataset_train: DetexifyDataset,
+    dataset_eval: DetexifyDataset
+) {
+    std::fs::create_dir_all(output_dir).unwrap();
+    let training_config = TrainingConfig::load(format!("{artifact_dir}/config.json"))
+        .expect("Config should exist for the model; run train first");
+    training_config
+        .save(format!("{output_dir}/config.json"))
+        .expect("Config should be saved successfully!");
+    config
+        .save(format!("{output_dir}/finetune.json"))
+        .expect("Fine-tune config should be saved successfully!");
+    std::fs::copy(format!("{artifact_dir}/labels.json"), format!("{output_dir}/labels.json"))
+        .expect("Labels should exist for the model; run train first");
+
+    B::seed(&device, config.seed);
+
+    let test_items: Vec<DetexifyItem> = dataset_eval.dataset.iter().collect();
+    let prototype_items: Vec<DetexifyItem> = dataset_train.dataset.iter().collect();
+    let model = load_model::<B>(artifact_dir, &device);
+
+    let before_corrections = accuracy(&model.valid(), &device, corrections.clone(), config.batch_size);
+    let before_test = accuracy(&model.valid(), &device, test_items.clone(), config.batch_size);
+
+    let mixed = mix_with_replay(&corrections, dataset_train, &config);
+    let model = fit(output_dir, model, &training_config, &config, device.clone(), mixed, test_items.clone());
+
+    let after_corrections = accuracy(&model, &device, corrections, config.batch_size);
+    let after_test = accuracy(&model, &device, test_items.clone(), config.batch_size);
+
+    println!("Accuracy         before   after");
+    println!("Corrections      {:>6.2}%  {:>6.2}%", before_corrections * 100.0, after_corrections * 100.0);
+    println!("Test split       {:>6.2}%  {:>6.2}%", before_test * 100.0, after_test * 100.0);
+
+    // The temperature and prototypes no longer match the fine-tuned weights
+    calibrate::<B::InnerBackend>(output_dir, device.clone(), test_items);
+    let labels = Labels::load(format!("{output_dir}/labels.json"))
+        .expect("Labels should have been copied to the output directory");
+    build_prototypes::<B::InnerBackend>(output_dir, device, prototype_items, &labels.keys);
+}
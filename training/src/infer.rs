@@ -6,15 +6,20 @@ use burn::module::Module;
 use burn::prelude::Backend;
 use burn::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
 use shared::item::DetexifyItem;
+use shared::model::Model;
 
-pub fn infer<B: Backend>(artifact_dir: &str, device: B::Device, item: DetexifyItem) {
+pub fn load_model<B: Backend>(artifact_dir: &str, device: &B::Device) -> Model<B> {
     let config = TrainingConfig::load(format!("{artifact_dir}/config.json"))
         .expect("Config should exist for the model; run train first");
     let record = BinFileRecorder::<FullPrecisionSettings>::new()
-        .load(format!("{artifact_dir}/model").into(), &device)
+        .load(format!("{artifact_dir}/model").into(), device)
         .expect("Trained model should exist; run train first");
 
-    let model = config.model.init::<B>(&device).load_record(record);
+    config.model.init::<B>(device).load_record(record)
+}
+
+pub fn infer<B: Backend>(artifact_dir: &str, device: B::Device, item: DetexifyItem) {
+    let model = load_model::<B>(artifact_dir, &device);
 
     let label = item.label;
     let batcher = DetexifyBatcher::default();
@@ -23,4 +28,4 @@ pub fn infer<B: Backend>(artifact_dir: &str, device: B::Device, item: DetexifyIt
     let predicted = output.argmax(1).flatten::<1>(0, 1).into_scalar();
 
     println!("Predicted: {}, Actual: {}", predicted, label);
-}
\ No newline at end of file
+}
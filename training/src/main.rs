@@ -3,10 +3,15 @@ mod data;
 mod training;
 mod infer;
 mod dataset;
+mod calibration;
+mod prototypes;
+mod finetune;
+mod expand;
 
 use crate::training::TrainingConfig;
 use burn::backend::{Autodiff, Vulkan};
-use burn::data::dataset::InMemDataset;
+use burn::config::Config;
+use burn::data::dataset::{Dataset, InMemDataset};
 use burn::optim::AdamWConfig;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::Error;
@@ -14,8 +19,10 @@ use std::collections::{HashMap, HashSet};
 use std::ops::Add;
 use shared::image_processing::{rasterize_strokes, save_image};
 use shared::item::{DetexifyItem, HEIGHT, WIDTH};
+use shared::labels::Labels;
 use shared::model::ModelConfig;
 use crate::dataset::DetexifyDataset;
+use crate::finetune::FineTuneConfig;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
@@ -87,14 +94,64 @@ async fn main() -> Result<(), Error> {
 
     let dataset = DetexifyDataset { dataset: InMemDataset::new(items.clone()) };
     let (dataset_train, dataset_eval) = dataset.split(0.8);
+    let calibration_items: Vec<DetexifyItem> = dataset_eval.dataset.iter().collect();
+    let prototype_items: Vec<DetexifyItem> = dataset_train.dataset.iter().collect();
 
     type MyBackend = Vulkan;
     type MyAutodiffBackend = Autodiff<MyBackend>;
 
     let device = Default::default();
-    let model = ModelConfig::new(number_of_classes as usize, 256).init::<MyBackend>(&device);
     let artifact_dir = "./models";
 
+    // `training finetune <corrections.json> [output_dir]` fine-tunes the existing checkpoint instead
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("finetune") {
+        let corrections_path = args.get(2).expect("Usage: training finetune <corrections.json> [output_dir]");
+        let output_dir = args.get(3).map(String::as_str).unwrap_or("./models-finetuned");
+
+        // The checkpoint's classes needn't be in database order, e.g. after `expand`
+        let labels = Labels::load(format!("{artifact_dir}/labels.json"))
+            .expect("Labels should exist for the model; run train first");
+        let corrections = finetune::load_corrections(corrections_path, &labels);
+        let dataset_train = DetexifyDataset {
+            dataset: InMemDataset::new(finetune::relabel(dataset_train.dataset.iter(), &distinct_keys, &labels))
+        };
+        let dataset_eval = DetexifyDataset {
+            dataset: InMemDataset::new(finetune::relabel(dataset_eval.dataset.iter(), &distinct_keys, &labels))
+        };
+
+        finetune::finetune::<MyAutodiffBackend>(
+            artifact_dir,
+            output_dir,
+            FineTuneConfig::new(),
+            device,
+            corrections,
+            dataset_train,
+            dataset_eval
+        );
+
+        return Ok(());
+    }
+
+    // `training expand [output_dir]` adds the keys the existing checkpoint doesn't know yet
+    if args.get(1).map(String::as_str) == Some("expand") {
+        let output_dir = args.get(2).map(String::as_str).unwrap_or("./models-expanded");
+
+        expand::expand_classes::<MyAutodiffBackend>(
+            artifact_dir,
+            output_dir,
+            FineTuneConfig::new().with_learning_rate(1.0e-4),
+            device,
+            &distinct_keys,
+            dataset_train,
+            dataset_eval
+        );
+
+        return Ok(());
+    }
+
+    let model = ModelConfig::new(number_of_classes as usize, 256).init::<MyBackend>(&device);
+
     training::train::<MyAutodiffBackend>(
         artifact_dir,
         TrainingConfig::new(
@@ -106,8 +163,18 @@ async fn main() -> Result<(), Error> {
         dataset_eval
     );
 
+    Labels::new(distinct_keys.clone())
+        .save(format!("{artifact_dir}/labels.json"))
+        .expect("Labels should be saved successfully!");
+
     println!("{model}");
 
+    calibration::calibrate::<MyBackend>(artifact_dir, device.clone(), calibration_items);
+
+    let index = prototypes::build_prototypes::<MyBackend>(artifact_dir, device.clone(), prototype_items, &distinct_keys);
+    prototypes::print_neighbours(&index, "latex2e-OT1-_sigma", 10);
+    prototypes::print_similar::<MyBackend>(artifact_dir, device.clone(), &index, sigma.clone(), 10);
+
     infer::infer::<MyBackend>(artifact_dir, device, sigma.clone());
 
     Ok(())
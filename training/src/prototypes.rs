@@ -0,0 +1,77 @@
+use crate::data::{DetexifyBatch, DetexifyBatcher};
+use crate::infer::load_model;
+use burn::config::Config;
+use burn::data::dataloader::batcher::Batcher;
+use burn::prelude::Backend;
+use shared::item::DetexifyItem;
+use shared::model::Model;
+use shared::prototypes::{mean_prototype, PrototypeIndex};
+use shared::symbols::SymbolCatalogue;
+
+/// Runs `Model::embed` over `items`, returning one row per item.
+pub fn collect_embeddings<B: Backend>(model: &Model<B>, device: &B::Device, items: &[DetexifyItem], batch_size: usize) -> Vec<Vec<f32>> {
+    let batcher = DetexifyBatcher::default();
+
+    items
+        .chunks(batch_size)
+        .flat_map(|chunk| {
+            let batch: DetexifyBatch<B> = batcher.batch(chunk.to_vec(), device);
+            let embeddings = model.embed(batch.images);
+            let [rows, dimension] = embeddings.dims();
+            let values = embeddings.into_data().convert::<f32>().to_vec::<f32>().unwrap();
+
+            (0..rows)
+                .map(|row| values[row * dimension..(row + 1) * dimension].to_vec())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Averages the embeddings of every class into `prototypes.json`.
+pub fn build_prototypes<B: Backend>(artifact_dir: &str, device: B::Device, items: Vec<DetexifyItem>, keys: &[String]) -> PrototypeIndex {
+    let model = load_model::<B>(artifact_dir, &device);
+    let embeddings = collect_embeddings(&model, &device, &items, 256);
+
+    let mut per_class: Vec<Vec<Vec<f32>>> = vec![Vec::new(); keys.len()];
+    for (item, embedding) in items.iter().zip(embeddings) {
+        per_class[item.label as usize].push(embedding);
+    }
+
+    let index = PrototypeIndex::new(
+        keys.to_vec(),
+        per_class.iter().map(|embeddings| mean_prototype(embeddings)).collect()
+    );
+
+    index
+        .save(format!("{artifact_dir}/prototypes.json"))
+        .expect("Prototype index should be saved successfully!");
+
+    index
+}
+
+/// `\command (key)`, or just the key if the catalogue doesn't know it.
+fn describe(key: &str) -> String {
+    match SymbolCatalogue::embedded().get(key) {
+        Some(symbol) => format!("{} ({key})", symbol.command),
+        None => key.to_string()
+    }
+}
+
+/// Prints the classes visually closest to `key`.
+pub fn print_neighbours(index: &PrototypeIndex, key: &str, k: usize) {
+    println!("Closest to {}:", describe(key));
+    for neighbour in index.nearest_to_class(key, k) {
+        println!("  {} {:.3}", describe(&neighbour.key), neighbour.similarity);
+    }
+}
+
+/// Prints the classes whose prototypes look most like a single drawing.
+pub fn print_similar<B: Backend>(artifact_dir: &str, device: B::Device, index: &PrototypeIndex, item: DetexifyItem, k: usize) {
+    let model = load_model::<B>(artifact_dir, &device);
+    let embedding = collect_embeddings(&model, &device, &[item], 1).remove(0);
+
+    println!("Most similar to drawing:");
+    for neighbour in index.nearest(&embedding, k) {
+        println!("  {} {:.3}", describe(&neighbour.key), neighbour.similarity);
+    }
+}
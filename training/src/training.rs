@@ -12,6 +12,7 @@ use burn::tensor::Int;
 use burn::train::metric::{AccuracyMetric, LossMetric, TopKAccuracyMetric};
 use burn::train::{ClassificationOutput, LearnerBuilder, LearningStrategy, TrainOutput, TrainStep, ValidStep};
 use burn::Tensor;
+use shared::calibration::DEFAULT_REJECTION_THRESHOLD;
 use shared::model::{Model, ModelConfig};
 
 pub trait ForwardClassification<B: Backend> {
@@ -65,7 +66,9 @@ pub struct TrainingConfig {
     #[config(default = 42)]
     pub seed: u64,
     #[config(default = 1.0e-3)]
-    pub learning_rate: f64
+    pub learning_rate: f64,
+    #[config(default = "DEFAULT_REJECTION_THRESHOLD")]
+    pub rejection_threshold: f64
 }
 
 fn create_artifact_dir(artifact_dir: &str) {
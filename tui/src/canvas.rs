@@ -0,0 +1,71 @@
+use std::time::Instant;
+
+// Drawing area in terminal cells; cells are about twice as tall as wide, so twice as many columns
+pub const COLUMNS: u16 = 64;
+pub const ROWS: u16 = 32;
+
+/// Strokes drawn with the mouse, in detexify's `[x, y, t]` format with square units.
+pub struct Canvas {
+    strokes: Vec<Vec<[f32; 3]>>,
+    drawing: bool,
+    start: Instant
+}
+
+impl Canvas {
+    pub fn new() -> Self {
+        Self { strokes: Vec::new(), drawing: false, start: Instant::now() }
+    }
+
+    fn point(&self, column: u16, row: u16) -> [f32; 3] {
+        [column as f32, row as f32 * 2.0, self.start.elapsed().as_millis() as f32]
+    }
+
+    /// Starts a stroke at a cell inside the canvas.
+    pub fn press(&mut self, column: u16, row: u16) {
+        self.strokes.push(vec![self.point(column, row)]);
+        self.drawing = true;
+    }
+
+    pub fn drag(&mut self, column: u16, row: u16) {
+        if !self.drawing {
+            return;
+        }
+        let point = self.point(column.min(COLUMNS - 1), row.min(ROWS - 1));
+        let stroke = self.strokes.last_mut().expect("A stroke is in progress");
+        if stroke.last().is_none_or(|last| last[..2] != point[..2]) {
+            stroke.push(point);
+        }
+    }
+
+    /// Ends the stroke in progress, returning whether there was one.
+    pub fn release(&mut self) -> bool {
+        std::mem::replace(&mut self.drawing, false)
+    }
+
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+        self.drawing = false;
+    }
+
+    pub fn strokes(&self) -> &Vec<Vec<[f32; 3]>> {
+        &self.strokes
+    }
+
+    /// Which cells the strokes pass through, row by row, joining consecutive points.
+    pub fn cells(&self) -> [[bool; COLUMNS as usize]; ROWS as usize] {
+        let mut cells = [[false; COLUMNS as usize]; ROWS as usize];
+        for stroke in &self.strokes {
+            let points: Vec<(i32, i32)> = stroke.iter().map(|&[x, y, _]| (x as i32, (y / 2.0) as i32)).collect();
+            for (index, &(x0, y0)) in points.iter().enumerate() {
+                let (x1, y1) = points.get(index + 1).copied().unwrap_or((x0, y0));
+                let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+                for step in 0..=steps {
+                    let x = x0 + (x1 - x0) * step / steps;
+                    let y = y0 + (y1 - y0) * step / steps;
+                    cells[y as usize][x as usize] = true;
+                }
+            }
+        }
+        cells
+    }
+}
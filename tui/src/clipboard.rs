@@ -0,0 +1,10 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::io::Write;
+
+/// Asks the terminal to put `text` on the system clipboard with an OSC 52 sequence, which also
+/// works over SSH. Terminals that don't support it ignore the sequence.
+pub fn copy_text(out: &mut impl Write, text: &str) -> std::io::Result<()> {
+    write!(out, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    out.flush()
+}
@@ -0,0 +1,133 @@
+mod canvas;
+mod clipboard;
+mod ui;
+
+use burn::backend::NdArray;
+use burn::backend::ndarray::NdArrayDevice;
+use canvas::Canvas;
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{
+    read, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind
+};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use shared::classifier::{OfflineClassifier, RankedSymbol};
+use shared::image_processing::rasterize_strokes;
+use shared::item::{HEIGHT, WIDTH};
+use shared::symbols::SymbolCatalogue;
+use std::io::{stdout, Stdout};
+
+// Training writes these to its artifact directory; copy them to the repository root (see the README)
+static STATE_ENCODED: &[u8] = include_bytes!("../../model.bin");
+static CALIBRATION_ENCODED: &[u8] = include_bytes!("../../calibration.json");
+static LABELS_ENCODED: &[u8] = include_bytes!("../../labels.json");
+
+// One per digit key
+const TOP_K: usize = 9;
+
+/// What's on screen: the drawing, the image the model saw and its ranking.
+pub struct App {
+    canvas: Canvas,
+    image: [[f32; WIDTH]; HEIGHT],
+    ranked: Vec<RankedSymbol>,
+    recognised: bool,
+    selected: usize,
+    status: String
+}
+
+impl App {
+    fn classify(&mut self, classifier: &OfflineClassifier<NdArray>) {
+        self.image = rasterize_strokes(self.canvas.strokes());
+        self.ranked = classifier.rank(self.image, TOP_K);
+        self.recognised = classifier.is_recognised(&self.ranked);
+        self.selected = 0;
+    }
+
+    fn clear(&mut self) {
+        self.canvas.clear();
+        self.image = [[0.0; WIDTH]; HEIGHT];
+        self.ranked.clear();
+        self.status.clear();
+    }
+
+    fn copy(&mut self, out: &mut Stdout, index: usize) -> std::io::Result<()> {
+        let Some(ranked) = self.ranked.get(index) else { return Ok(()) };
+        self.selected = index;
+        let Some(symbol) = SymbolCatalogue::embedded().get(&ranked.key) else { return Ok(()) };
+        clipboard::copy_text(out, &symbol.command)?;
+        self.status = match symbol.preamble().as_slice() {
+            [] => format!("Copied {}", symbol.command),
+            preamble => format!("Copied {}, which needs {}", symbol.command, preamble.join(" "))
+        };
+        Ok(())
+    }
+}
+
+/// Raw mode, the alternate screen and mouse capture for as long as it lives, so the terminal
+/// is restored however the program exits.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter(out: &mut Stdout) -> std::io::Result<Self> {
+        enable_raw_mode()?;
+        // Mouse capture turns on the xterm button-event and SGR mouse reporting sequences
+        execute!(out, EnterAlternateScreen, EnableMouseCapture, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, DisableMouseCapture, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let classifier = OfflineClassifier::<NdArray>::from_bytes(
+        STATE_ENCODED, CALIBRATION_ENCODED, LABELS_ENCODED, NdArrayDevice::default()
+    ).map_err(std::io::Error::other)?;
+    let mut out = stdout();
+    let _terminal = TerminalGuard::enter(&mut out)?;
+    let mut app = App {
+        canvas: Canvas::new(),
+        image: [[0.0; WIDTH]; HEIGHT],
+        ranked: Vec::new(),
+        recognised: false,
+        selected: 0,
+        status: String::new()
+    };
+
+    loop {
+        ui::draw(&mut out, &app)?;
+        match read()? {
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    if let Some((column, row)) = ui::canvas_cell(mouse.column, mouse.row) {
+                        app.canvas.press(column, row);
+                    }
+                }
+                MouseEventKind::Drag(MouseButton::Left) => {
+                    // Strokes leaving the canvas stick to its edge
+                    let column = mouse.column.saturating_sub(ui::CANVAS_LEFT);
+                    let row = mouse.row.saturating_sub(ui::CANVAS_TOP);
+                    app.canvas.drag(column, row);
+                }
+                MouseEventKind::Up(MouseButton::Left) if app.canvas.release() => app.classify(&classifier),
+                _ => {}
+            },
+            Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('c') => app.clear(),
+                KeyCode::Char(digit @ '1'..='9') => app.copy(&mut out, digit as usize - '1' as usize)?,
+                KeyCode::Up => app.selected = app.selected.saturating_sub(1),
+                KeyCode::Down => app.selected = (app.selected + 1).min(app.ranked.len().saturating_sub(1)),
+                KeyCode::Enter => app.copy(&mut out, app.selected)?,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::App;
+use crate::canvas::{COLUMNS, ROWS};
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{Clear, ClearType};
+use shared::item::{HEIGHT, WIDTH};
+use shared::symbols::SymbolCatalogue;
+use std::io::Write;
+
+// Top-left cell of the drawing area, inside its border
+pub const CANVAS_LEFT: u16 = 1;
+pub const CANVAS_TOP: u16 = 2;
+// Left edge of the preview and results column
+const SIDE_LEFT: u16 = CANVAS_LEFT + COLUMNS + 3;
+
+/// Maps a terminal cell to a canvas cell, if it's inside the drawing area.
+pub fn canvas_cell(column: u16, row: u16) -> Option<(u16, u16)> {
+    let column = column.checked_sub(CANVAS_LEFT).filter(|&column| column < COLUMNS)?;
+    let row = row.checked_sub(CANVAS_TOP).filter(|&row| row < ROWS)?;
+    Some((column, row))
+}
+
+fn grey(intensity: f32) -> Color {
+    let level = (intensity.clamp(0.0, 1.0) * 255.0) as u8;
+    Color::Rgb { r: level, g: level, b: level }
+}
+
+/// Redraws the whole screen: the canvas, the 32x32 model input, the ranked commands and the status line.
+pub fn draw(out: &mut impl Write, app: &App) -> std::io::Result<()> {
+    queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+    queue!(out, Print("Draw a symbol with the mouse · 1-9 or ↑↓ Enter to copy · c clear · q quit"))?;
+
+    // Canvas border and strokes
+    let horizontal = "─".repeat(COLUMNS as usize);
+    queue!(out, MoveTo(CANVAS_LEFT - 1, CANVAS_TOP - 1), Print(format!("┌{horizontal}┐")))?;
+    for (row, cells) in app.canvas.cells().iter().enumerate() {
+        let line: String = cells.iter().map(|&inked| if inked { '█' } else { ' ' }).collect();
+        queue!(out, MoveTo(CANVAS_LEFT - 1, CANVAS_TOP + row as u16), Print(format!("│{line}│")))?;
+    }
+    queue!(out, MoveTo(CANVAS_LEFT - 1, CANVAS_TOP + ROWS), Print(format!("└{horizontal}┘")))?;
+
+    // Two pixels per cell: the top one as the foreground of '▀', the bottom one as its background
+    queue!(out, MoveTo(SIDE_LEFT, CANVAS_TOP - 1), Print(format!("Model input ({WIDTH}x{HEIGHT})")))?;
+    for row in 0..HEIGHT / 2 {
+        queue!(out, MoveTo(SIDE_LEFT, CANVAS_TOP + row as u16))?;
+        for column in 0..WIDTH {
+            let (top, bottom) = (app.image[row * 2][column], app.image[row * 2 + 1][column]);
+            queue!(out, SetForegroundColor(grey(top)), SetBackgroundColor(grey(bottom)), Print('▀'))?;
+        }
+        queue!(out, ResetColor)?;
+    }
+
+    let results_top = CANVAS_TOP + HEIGHT as u16 / 2 + 1;
+    let heading = if app.ranked.is_empty() || app.recognised { "Results" } else { "Not recognised, best guesses" };
+    queue!(out, MoveTo(SIDE_LEFT, results_top), Print(heading))?;
+    let catalogue = SymbolCatalogue::embedded();
+    for (index, ranked) in app.ranked.iter().enumerate() {
+        let symbol = catalogue.get(&ranked.key);
+        let command = symbol.map_or(ranked.key.as_str(), |symbol| symbol.command.as_str());
+        let package = symbol.map_or("", |symbol| symbol.package.as_str());
+        let line = format!("{} {command:<24} {package:<10} {:>5.1}%", index + 1, ranked.probability * 100.0);
+        queue!(out, MoveTo(SIDE_LEFT, results_top + 1 + index as u16))?;
+        if index == app.selected {
+            queue!(out, SetAttribute(Attribute::Reverse), Print(line), SetAttribute(Attribute::Reset))?;
+        } else {
+            queue!(out, Print(line))?;
+        }
+    }
+
+    queue!(out, MoveTo(0, CANVAS_TOP + ROWS + 1), Print(&app.status))?;
+    out.flush()
+}
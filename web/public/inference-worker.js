@@ -0,0 +1,13 @@
+// Module worker that runs the classifier; see `start_inference_worker` in src/lib.rs
+import init, { start_inference_worker } from '/pkg/type-it-web.js';
+
+// Hold on to requests posted while the wasm module is still loading
+const early = [];
+self.onmessage = event => early.push(event);
+
+await init();
+start_inference_worker();
+
+for (const event of early) {
+  self.onmessage(event);
+}
@@ -1,5 +1,5 @@
 // IMPORTANT: Increment this version number when you push updates
-const CACHE_VERSION = 'v3';
+const CACHE_VERSION = 'v4';
 const CACHE_NAME = `typeit-${CACHE_VERSION}`;
 const urlsToCache = [
   '/',
@@ -9,7 +9,8 @@ const urlsToCache = [
   '/computer-modern.otf',
   '/icon-192.png',
   '/icon-512.png',
-  '/manifest.json'
+  '/manifest.json',
+  '/inference-worker.js'
 ];
 
 self.addEventListener('install', event => {
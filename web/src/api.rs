@@ -0,0 +1,321 @@
+use crate::app::classifier::model::{Classification, SharedModel, BATCH_SIZE};
+use crate::app::classifier::protocol::InferenceInput;
+use crate::app::classifier::state::CpuBackend;
+use crate::batch::{classify_all, BatchLine};
+use axum::body::Bytes;
+use axum::extract::{DefaultBodyLimit, Query, State};
+use axum::http::{header, HeaderMap, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use burn::backend::ndarray::NdArrayDevice;
+use serde::{Deserialize, Serialize};
+use shared::classifier::RankedSymbol;
+use shared::custom_symbols::CustomSymbolLibrary;
+use shared::image_processing::image_from_bytes;
+use shared::item::{HEIGHT, WIDTH};
+use shared::search::search;
+use shared::symbols::{Symbol, SymbolCatalogue};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::runtime::Handle;
+use tokio::sync::Mutex;
+
+mod detexify;
+
+static OPENAPI_JSON: &str = include_str!("../openapi.json");
+
+// Generous for hand-drawn strokes or a glyph crop, small enough to refuse anything else
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+const MAX_BATCH_BODY_BYTES: usize = 16 * 1024 * 1024;
+const MAX_BATCH_INPUTS: usize = 1000;
+const MAX_STROKES: usize = 200;
+const MAX_POINTS: usize = 20_000;
+pub const MAX_K: usize = 50;
+pub const DEFAULT_K: usize = 5;
+
+/// The model shared by every request, on the CPU backend.
+#[derive(Clone)]
+pub struct ApiState {
+    model: Arc<Mutex<SharedModel<CpuBackend>>>
+}
+
+impl ApiState {
+    pub fn new() -> Self {
+        Self { model: Arc::new(Mutex::new(SharedModel::new(NdArrayDevice::default()))) }
+    }
+
+    /// Runs `inference` with the model on a blocking thread: NdArray inference is CPU-bound and
+    /// would otherwise stall the runtime's workers, and requests queue for the lock off them too.
+    async fn run<T, F>(&self, inference: F) -> T
+    where
+        T: Send + 'static,
+        F: AsyncFnOnce(&mut SharedModel<CpuBackend>) -> T + Send + 'static
+    {
+        let model = self.model.clone();
+        let runtime = Handle::current();
+        tokio::task::spawn_blocking(move || runtime.block_on(inference(&mut model.blocking_lock())))
+            .await
+            .expect("Inference panicked")
+    }
+
+    /// Classifies one drawing; custom symbols only exist in the browser, so none are scored here.
+    pub async fn classify(&self, image: [[f32; WIDTH]; HEIGHT], k: usize) -> Classification {
+        self.run(async move |model| model.inference(image, &CustomSymbolLibrary::new(), k).await).await
+    }
+
+    /// The `k` most likely symbols, best first, even if none passes the rejection threshold.
+    pub async fn ranked(&self, image: [[f32; WIDTH]; HEIGHT], k: usize) -> Vec<RankedSymbol> {
+        self.run(async move |model| model.ranked(image, &CustomSymbolLibrary::new(), k).await).await
+    }
+
+    /// Classifies `images` one model batch at a time, releasing the model between batches so
+    /// single requests aren't stuck behind a large batch.
+    pub async fn classify_batch(&self, images: &[[[f32; WIDTH]; HEIGHT]], k: usize) -> Vec<Classification> {
+        let mut classifications = Vec::with_capacity(images.len());
+        for chunk in images.chunks(BATCH_SIZE) {
+            let chunk = chunk.to_vec();
+            classifications.extend(self.run(async move |model| model.inference_batch(&chunk, k).await).await);
+        }
+        classifications
+    }
+}
+
+impl Default for ApiState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A request the API refuses, answered as `{"error": "..."}`.
+pub struct ApiError(StatusCode, String);
+
+impl ApiError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self(StatusCode::BAD_REQUEST, message.into())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(ErrorBody { error: self.1 })).into_response()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct StrokesRequest {
+    /// Detexify strokes: `[[[x, y, t], ...], ...]`.
+    pub strokes: Vec<Vec<[f32; 3]>>,
+    pub k: Option<usize>
+}
+
+#[derive(Deserialize)]
+pub struct BatchRequest {
+    pub inputs: Vec<BatchInput>,
+    pub k: Option<usize>
+}
+
+/// One drawing in a batch: detexify strokes, or a base64-encoded PNG crop like `/api/classify` takes.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum BatchInput {
+    Strokes { strokes: Vec<Vec<[f32; 3]>> },
+    Png { png: String }
+}
+
+impl BatchInput {
+    fn into_image(self) -> Result<[[f32; WIDTH]; HEIGHT], ApiError> {
+        match self {
+            BatchInput::Strokes { strokes } => {
+                validate_strokes(&strokes)?;
+                Ok(InferenceInput::Strokes(strokes).to_image())
+            }
+            BatchInput::Png { png } => {
+                let bytes = STANDARD.decode(png).map_err(|error| ApiError::bad_request(format!("invalid base64: {error}")))?;
+                image_from_bytes(&bytes).map_err(|error| ApiError::bad_request(format!("invalid PNG: {error}")))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ClassifyQuery {
+    pub k: Option<usize>
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ApiPrediction {
+    pub key: String,
+    pub command: String,
+    pub package: String,
+    /// Calibrated, between 0 and 1.
+    pub probability: f32
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ClassifyResponse {
+    /// False when even the best guess falls below the rejection threshold; `predictions` is then empty.
+    pub recognised: bool,
+    pub predictions: Vec<ApiPrediction>,
+    pub latency_ms: f64
+}
+
+#[derive(Serialize)]
+struct SymbolEntry<'a> {
+    key: &'a str,
+    command: &'a str,
+    package: &'a str,
+    encoding: &'a str,
+    mathmode: bool,
+    textmode: bool
+}
+
+impl<'a> From<&'a Symbol> for SymbolEntry<'a> {
+    fn from(symbol: &'a Symbol) -> Self {
+        Self {
+            key: &symbol.id,
+            command: &symbol.command,
+            package: &symbol.package,
+            encoding: symbol.encoding(),
+            mathmode: symbol.mathmode,
+            textmode: symbol.textmode
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SymbolsQuery {
+    q: Option<String>
+}
+
+/// Rejects drawings too large to be a single symbol.
+pub fn validate_strokes(strokes: &[Vec<[f32; 3]>]) -> Result<(), ApiError> {
+    if strokes.is_empty() || strokes.iter().all(|stroke| stroke.is_empty()) {
+        return Err(ApiError::bad_request("strokes is empty"));
+    }
+    if strokes.len() > MAX_STROKES {
+        return Err(ApiError::bad_request(format!("at most {MAX_STROKES} strokes are accepted")));
+    }
+    if strokes.iter().map(Vec::len).sum::<usize>() > MAX_POINTS {
+        return Err(ApiError::bad_request(format!("at most {MAX_POINTS} points are accepted")));
+    }
+    Ok(())
+}
+
+pub fn validate_k(k: Option<usize>) -> Result<usize, ApiError> {
+    match k.unwrap_or(DEFAULT_K) {
+        k @ 1..=MAX_K => Ok(k),
+        _ => Err(ApiError::bad_request(format!("k must be between 1 and {MAX_K}")))
+    }
+}
+
+/// The response body for a classification, with catalogue details for each key.
+pub fn classify_response(classification: Classification, latency_ms: f64) -> ClassifyResponse {
+    let catalogue = SymbolCatalogue::embedded();
+    match classification {
+        Classification::Recognised(predictions) => ClassifyResponse {
+            recognised: true,
+            predictions: predictions.into_iter()
+                .map(|prediction| {
+                    let symbol = catalogue.get(&prediction.key);
+                    ApiPrediction {
+                        command: symbol.map(|symbol| symbol.command.clone()).unwrap_or_default(),
+                        package: symbol.map(|symbol| symbol.package.clone()).unwrap_or_default(),
+                        key: prediction.key,
+                        probability: prediction.probability
+                    }
+                })
+                .collect(),
+            latency_ms
+        },
+        Classification::NotRecognised => ClassifyResponse { recognised: false, predictions: Vec::new(), latency_ms }
+    }
+}
+
+/// `POST /api/classify`: a JSON body of strokes, or a PNG body with `k` in the query string.
+async fn classify(
+    State(state): State<ApiState>,
+    Query(query): Query<ClassifyQuery>,
+    headers: HeaderMap,
+    body: Bytes
+) -> Result<Json<ClassifyResponse>, ApiError> {
+    let content_type = headers.get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let (image, k) = if content_type.starts_with("image/png") {
+        let image = image_from_bytes(&body).map_err(|error| ApiError::bad_request(format!("invalid PNG: {error}")))?;
+        (image, validate_k(query.k)?)
+    } else if content_type.starts_with("application/json") {
+        let request: StrokesRequest = serde_json::from_slice(&body)
+            .map_err(|error| ApiError::bad_request(format!("invalid JSON: {error}")))?;
+        validate_strokes(&request.strokes)?;
+        let k = validate_k(request.k.or(query.k))?;
+        (InferenceInput::Strokes(request.strokes).to_image(), k)
+    } else {
+        return Err(ApiError(StatusCode::UNSUPPORTED_MEDIA_TYPE, "send application/json strokes or an image/png".to_string()));
+    };
+
+    let start = Instant::now();
+    let classification = state.classify(image, k).await;
+    Ok(Json(classify_response(classification, start.elapsed().as_secs_f64() * 1000.0)))
+}
+
+/// `POST /api/classify/batch`: many drawings at once, answered as JSONL in input order,
+/// with the total model time in a `Server-Timing` header.
+async fn classify_batch(State(state): State<ApiState>, Json(request): Json<BatchRequest>) -> Result<Response, ApiError> {
+    if request.inputs.is_empty() || request.inputs.len() > MAX_BATCH_INPUTS {
+        return Err(ApiError::bad_request(format!("send between 1 and {MAX_BATCH_INPUTS} inputs")));
+    }
+    let k = validate_k(request.k)?;
+    let mut images = Vec::with_capacity(request.inputs.len());
+    for (index, input) in request.inputs.into_iter().enumerate() {
+        images.push(input.into_image().map_err(|ApiError(status, message)| ApiError(status, format!("input {index}: {message}")))?);
+    }
+
+    let (results, total_ms) = classify_all(&state, &images, k).await;
+    let mut body = String::new();
+    for (input, result) in results.into_iter().enumerate() {
+        body.push_str(&serde_json::to_string(&BatchLine { input, result }).expect("Failed to serialise a result"));
+        body.push('\n');
+    }
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson".to_string()), (HeaderName::from_static("server-timing"), format!("classify;dur={total_ms:.1}"))],
+        body
+    ).into_response())
+}
+
+/// `GET /api/symbols`: the whole catalogue, or the matches for `?q=`.
+async fn symbols(Query(query): Query<SymbolsQuery>) -> Response {
+    let catalogue = SymbolCatalogue::embedded();
+    let entries: Vec<SymbolEntry> = match query.q.as_deref() {
+        Some(q) => search(catalogue, q).into_iter().map(SymbolEntry::from).collect(),
+        None => catalogue.iter().map(SymbolEntry::from).collect()
+    };
+    Json(entries).into_response()
+}
+
+async fn openapi() -> Response {
+    ([(header::CONTENT_TYPE, "application/json")], OPENAPI_JSON).into_response()
+}
+
+/// The `/api` routes and the detexify-compatible `/classify`, merged into the Leptos router in `main.rs`.
+pub fn router<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    Router::new()
+        .route("/api/classify", post(classify))
+        .route("/api/classify/batch", post(classify_batch).layer(DefaultBodyLimit::max(MAX_BATCH_BODY_BYTES)))
+        .route("/api/symbols", get(symbols))
+        // Same path and shape as detexify's own server
+        .route("/classify", post(detexify::classify))
+        .route("/api/openapi.json", get(openapi))
+        .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
+        .with_state(ApiState::new())
+}
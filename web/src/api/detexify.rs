@@ -0,0 +1,57 @@
+use crate::api::{validate_strokes, ApiError, ApiState};
+use crate::app::classifier::protocol::InferenceInput;
+use axum::extract::State;
+use axum::{Form, Json};
+use serde::{Deserialize, Serialize};
+
+/// A point as the original detexify client sends it, `{"x": .., "y": .., "t": ..}`, or as `[x, y, t]`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DetexifyPoint {
+    Object {
+        x: f32,
+        y: f32,
+        #[serde(default)]
+        t: f32
+    },
+    Array([f32; 3])
+}
+
+impl DetexifyPoint {
+    fn to_array(&self) -> [f32; 3] {
+        match *self {
+            DetexifyPoint::Object { x, y, t } => [x, y, t],
+            DetexifyPoint::Array(point) => point
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DetexifyForm {
+    /// The strokes as a JSON string, the way detexify's form posts them.
+    strokes: String
+}
+
+#[derive(Serialize)]
+pub struct DetexifyScore {
+    id: String,
+    /// Lower is better, like detexify's distances: one minus the calibrated probability.
+    score: f32
+}
+
+/// `POST /classify`: detexify's classify endpoint, so existing plugins and scripts work unchanged.
+/// Every class comes back ranked, with no rejection threshold, as detexify never refused a drawing.
+pub async fn classify(State(state): State<ApiState>, Form(form): Form<DetexifyForm>) -> Result<Json<Vec<DetexifyScore>>, ApiError> {
+    let strokes: Vec<Vec<DetexifyPoint>> = serde_json::from_str(&form.strokes)
+        .map_err(|error| ApiError::bad_request(format!("invalid strokes: {error}")))?;
+    let strokes: Vec<Vec<[f32; 3]>> = strokes.iter()
+        .map(|stroke| stroke.iter().map(DetexifyPoint::to_array).collect())
+        .collect();
+    validate_strokes(&strokes)?;
+
+    // k is clamped to the number of classes, so this ranks all of them
+    let predictions = state.ranked(InferenceInput::Strokes(strokes).to_image(), usize::MAX).await;
+    Ok(Json(predictions.into_iter()
+        .map(|prediction| DetexifyScore { id: prediction.key, score: 1.0 - prediction.probability })
+        .collect()))
+}
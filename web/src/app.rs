@@ -2,15 +2,20 @@ use leptos::prelude::*;
 use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
 use leptos_router::{
     components::{Route, Router, Routes},
-    StaticSegment,
+    ParamSegment, StaticSegment,
 };
 use crate::app::classifier::Classifier;
 use crate::app::footer::Footer;
 use crate::app::install_button::InstallButton;
+use crate::app::symbol_browser::SymbolBrowser;
+use crate::app::symbol_detail::SymbolDetail;
 
-mod classifier;
+pub(crate) mod classifier;
 mod footer;
+mod glyph;
 mod install_button;
+mod symbol_browser;
+mod symbol_detail;
 
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     view! {
@@ -60,6 +65,8 @@ pub fn App() -> impl IntoView {
             <main>
                 <Routes fallback=|| "Page not found.".into_view()>
                     <Route path=StaticSegment("") view=HomePage/>
+                    <Route path=StaticSegment("symbols") view=SymbolBrowser/>
+                    <Route path=(StaticSegment("symbol"), ParamSegment("key")) view=SymbolDetail/>
                 </Routes>
             </main>
         </Router>
@@ -76,6 +83,7 @@ fn HomePage() -> impl IntoView {
                 "Symbol Classifier"
             </h1>
             <InstallButton />
+            <p><a href="/symbols">"Browse all symbols"</a></p>
             <Classifier />
             <Footer />
         </>
@@ -1,241 +1,400 @@
-use crate::app::classifier::state::{build_and_load_model, MyB};
 use burn::backend::ndarray::NdArrayDevice;
 use burn::tensor::activation::softmax;
 use leptos::control_flow::For;
-use leptos::prelude::{signal, Effect, Get, NodeRef, NodeRefAttribute, OnAttribute, Set, Show, StyleAttribute, IntoAny};
-use leptos::prelude::{ClassAttribute, ElementChild};
+use leptos::prelude::{signal, Effect, Get, Memo, NodeRef, Signal, NodeRefAttribute, OnAttribute, Set, Show, StyleAttribute, IntoAny};
+use leptos::prelude::{ClassAttribute, ElementChild, GlobalAttributes};
+use leptos::prelude::{event_target_value, GetUntracked, PropAttribute, Update};
+use leptos::prelude::{on_cleanup, provide_context, set_timeout, use_context, window_event_listener, RwSignal};
+use leptos::ev;
 use leptos::wasm_bindgen::JsCast;
 use leptos::{component, view, IntoView};
 use leptos::html::{Custom, InnerHtmlAttribute};
-use shared::item::{HEIGHT, WIDTH};
+use shared::custom_symbols::{CustomSymbolLibrary, CUSTOM_KEY_PREFIX, MAX_EXAMPLES, MIN_EXAMPLES};
+use shared::symbols::SymbolCatalogue;
 use shared::model::Model;
 use std::cell::RefCell;
 use std::hash::{Hash, Hasher};
 use std::iter::zip;
 use std::rc::Rc;
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
-use crate::app::classifier::model::{Prediction, SharedModel};
-
-mod keys;
-mod state;
-mod model;
-
-fn get_logical_coords(e: &web_sys::MouseEvent, canvas: &HtmlCanvasElement) -> (f64, f64) {
+use std::time::Duration;
+use shared::item::{HEIGHT, WIDTH};
+use web_sys::HtmlCanvasElement;
+use crate::app::classifier::custom::{export_library, import_library, load_library, save_library};
+use crate::app::classifier::client::InferenceClient;
+use crate::app::classifier::clipboard::copy_text;
+use crate::app::glyph::SymbolGlyph;
+use crate::app::symbol_detail::symbol_path;
+use crate::app::classifier::model::Classification;
+use crate::app::classifier::protocol::{InferenceBackend, InferenceInput, WorkerResponse};
+use crate::app::classifier::strokes::{fit_to_container, render_model_input, render_strokes, stroke_at, timestamp, to_detexify, History, Stroke};
+
+mod client;
+mod clipboard;
+mod custom;
+#[cfg(feature = "hydrate")]
+mod engine;
+pub(crate) mod state;
+pub(crate) mod model;
+pub(crate) mod protocol;
+mod strokes;
+#[cfg(feature = "hydrate")]
+pub(crate) mod worker;
+
+// Stroke coordinates are CSS pixels relative to the canvas, independent of its backing size
+fn get_pointer_coords(e: &web_sys::PointerEvent, canvas: &HtmlCanvasElement) -> (f64, f64) {
     let rect = canvas.get_bounding_client_rect();
-    let scale_x = canvas.width() as f64 / rect.width();
-    let scale_y = canvas.height() as f64 / rect.height();
 
-    let x = (e.client_x() as f64 - rect.left()) * scale_x;
-    let y = (e.client_y() as f64 - rect.top()) * scale_y;
+    let x = e.client_x() as f64 - rect.left();
+    let y = e.client_y() as f64 - rect.top();
 
     (x, y)
 }
 
-fn get_touch_coords(e: &web_sys::TouchEvent, canvas: &HtmlCanvasElement) -> Option<(f64, f64)> {
-    let touch = e.touches().get(0)?;
-    let rect = canvas.get_bounding_client_rect();
-    let scale_x = canvas.width() as f64 / rect.width();
-    let scale_y = canvas.height() as f64 / rect.height();
+/// The high-frequency samples the browser merged into `e`, or just `e` if it reports none.
+fn coalesced_events(e: &web_sys::PointerEvent) -> Vec<web_sys::PointerEvent> {
+    let events: Vec<web_sys::PointerEvent> = e.get_coalesced_events()
+        .iter()
+        .filter_map(|event| event.dyn_into::<web_sys::PointerEvent>().ok())
+        .collect();
+
+    if events.is_empty() { vec![e.clone()] } else { events }
+}
 
-    let x = (touch.client_x() as f64 - rect.left()) * scale_x;
-    let y = (touch.client_y() as f64 - rect.top()) * scale_y;
+// How close, in CSS pixels, a tap must land to a stroke to erase it
+const ERASER_TOLERANCE: f32 = 10.0;
 
-    Some((x, y))
+// Candidates listed before "Show more", and the number fetched so the list can expand instantly
+const VISIBLE_RESULTS: usize = 5;
+const MAX_RESULTS: usize = 20;
+
+// Minimum time between classifications sent while a stroke is still being drawn
+const LIVE_INTERVAL_MS: f32 = 150.0;
+
+// How long the "Copied" confirmation stays on screen
+const TOAST_DURATION: Duration = Duration::from_millis(2000);
+
+/// A short confirmation at the bottom of the page, shared with the prediction cards as context.
+/// Each message carries an id so an older timeout doesn't hide a newer message.
+#[derive(Clone, Copy)]
+struct Toast(RwSignal<Option<(u64, String)>>);
+
+impl Toast {
+    fn show(self, message: String) {
+        let Toast(toast) = self;
+        let id = toast.get_untracked().map_or(0, |(id, _)| id + 1);
+        toast.set(Some((id, message)));
+        set_timeout(move || {
+            if toast.get_untracked().is_some_and(|(current, _)| current == id) {
+                toast.set(None);
+            }
+        }, TOAST_DURATION);
+    }
 }
 
+/// `live_interval_ms` throttles classification during a stroke; zero or less waits for pen-up.
 #[component]
-pub fn Classifier() -> impl IntoView {
-    let model = Rc::new(RefCell::new(SharedModel::new()));
+pub fn Classifier(#[prop(default = LIVE_INTERVAL_MS)] live_interval_ms: f32) -> impl IntoView {
+    // Started on mount, since there is no worker to talk to during server rendering
+    let client = Rc::new(RefCell::new(None::<InferenceClient>));
     let canvas_ref = NodeRef::<leptos::html::Canvas>::new();
+    let toast = RwSignal::new(None::<(u64, String)>);
+    provide_context(Toast(toast));
     let processed_canvas_ref = NodeRef::<leptos::html::Canvas>::new();
     let (drawing, set_drawing) = signal(false);
-    let (prediction, set_predictions) = signal(None::<Vec<Prediction>>);
-    let (classifying, set_classifying) = signal(false);
+    // Everything drawn so far; the canvas and the classifier input are both derived from this
+    let (strokes, set_strokes) = signal(Vec::<Stroke>::new());
+    // The exact WIDTH x HEIGHT tensor last passed to the model, for the debug view
+    let (model_input, set_model_input) = signal(None::<[[f32; WIDTH]; HEIGHT]>);
+    let (show_model_input, set_show_model_input) = signal(false);
+    let (prediction, set_predictions) = signal(None::<Classification>);
+    let (custom_symbols, set_custom_symbols) = signal(CustomSymbolLibrary::new());
+    let (custom_command, set_custom_command) = signal(String::new());
+    let (custom_message, set_custom_message) = signal(None::<String>);
+    // Reported by the worker, for the diagnostics panel
+    let (backend, set_backend) = signal(None::<InferenceBackend>);
+    let (latency, set_latency) = signal(None::<f64>);
+    let client_for_custom = Rc::clone(&client);
+    let client_for_symbols = Rc::clone(&client);
+    let client_for_mount = Rc::clone(&client);
+    let client_for_live = Rc::clone(&client);
+
+    // Bumped after every completed edit (stroke, undo, redo, erase, clear) to reclassify
+    let (revision, set_revision) = signal(0usize);
+    let (history, set_history) = signal(History::default());
+    let (erasing, set_erasing) = signal(false);
+
+    let edited = move || set_revision.update(|revision| *revision += 1);
+
+    let erase_at = move |x: f64, y: f64| {
+        let current = strokes.get_untracked();
+        if let Some(index) = stroke_at(&current, x as f32, y as f32, ERASER_TOLERANCE) {
+            set_history.update(|history| history.record(current.clone()));
+            set_strokes.update(|strokes| {
+                strokes.remove(index);
+            });
+            edited();
+        }
+    };
 
-    // Initialize canvas drawing on mount
-    let setup_canvas = move || {
-        if let Some(canvas) = canvas_ref.get() {
-            let canvas: HtmlCanvasElement = canvas.clone().into();
-            let ctx = canvas
-                .get_context("2d")
-                .unwrap()
-                .unwrap()
-                .dyn_into::<CanvasRenderingContext2d>()
-                .unwrap();
-
-            ctx.set_line_width(1.5);
-            ctx.set_line_cap("round");
-            ctx.set_stroke_style_str("black");
+    let start_stroke = move |x: f64, y: f64, pressure: f32| {
+        if erasing.get_untracked() {
+            erase_at(x, y);
+            return;
+        }
+        set_history.update(|history| history.record(strokes.get_untracked()));
+        set_drawing.set(true);
+        set_strokes.update(|strokes| strokes.push(Stroke::new(x, y, pressure)));
+    };
+
+    let finish_stroke = move || {
+        if drawing.get_untracked() {
+            set_drawing.set(false);
+            // Final classification when done drawing
+            edited();
         }
     };
 
-    let perform_classification = Rc::new(move || {
-        if classifying.get() {
-            return; // Skip if already classifying
+    let undo = move || {
+        let mut restored = None;
+        set_history.update(|history| restored = history.undo(strokes.get_untracked()));
+        if let Some(previous) = restored {
+            set_strokes.set(previous);
+            edited();
+        }
+    };
+
+    let redo = move || {
+        let mut restored = None;
+        set_history.update(|history| restored = history.redo(strokes.get_untracked()));
+        if let Some(next) = restored {
+            set_strokes.set(next);
+            edited();
+        }
+    };
+
+    // Only one pointer draws at a time; a resting palm or second finger is ignored
+    let (active_pointer, set_active_pointer) = signal(None::<i32>);
+
+    let on_pointer_down = move |e: web_sys::PointerEvent| {
+        let is_main_button = e.pointer_type() != "mouse" || e.button() == 0;
+        if active_pointer.get_untracked().is_some() || !e.is_primary() || !is_main_button {
+            return;
         }
-        set_classifying.set(true);
         if let Some(canvas) = canvas_ref.get() {
-            // Spawn async task to avoid blocking the canvas
-            let model_inner = Rc::clone(&model);
-            wasm_bindgen_futures::spawn_local(async move {
-                let canvas: HtmlCanvasElement = canvas.clone().into();
-                let ctx = canvas
-                    .get_context("2d")
-                    .unwrap()
-                    .unwrap()
-                    .dyn_into::<CanvasRenderingContext2d>()
-                    .unwrap();
-
-                // Get image data from canvas
-                let image_data_obj = ctx
-                    .get_image_data(0.0, 0.0, canvas.width() as f64, canvas.height() as f64)
-                    .unwrap();
-
-                let data = image_data_obj.data();
-                let canvas_width = canvas.width() as usize;
-                let canvas_height = canvas.height() as usize;
-
-                // Create a temporary ImageBuffer for resizing
-                let img_buffer = image::ImageBuffer::from_fn(canvas_width as u32, canvas_height as u32, |x, y| {
-                    let luma_idx = (y as usize * canvas_width + x as usize) * 4 + 3; // * 4 + 3 to get the luma component
-                    image::Luma([data.0[luma_idx]])
-                });
+            e.prevent_default();
+            // Keep receiving moves for this pointer even once it leaves the canvas
+            let _ = canvas.set_pointer_capture(e.pointer_id());
+            set_active_pointer.set(Some(e.pointer_id()));
 
-                // Resize to 32x32
-                let resized = image::imageops::resize(&img_buffer, 32, 32, image::imageops::FilterType::Triangle);
+            let (x, y) = get_pointer_coords(&e, &canvas);
+            start_stroke(x, y, e.pressure());
+        }
+    };
 
-                // Convert to [[f32; 32]; 32] and normalize
-                let mut image_array = [[0.0f32; WIDTH]; HEIGHT];
-                for y in 0..HEIGHT {
-                    for x in 0..WIDTH {
-                        let pixel = resized.get_pixel(x as u32, y as u32);
+    // When the last live classification was sent, in `timestamp()` milliseconds
+    let (last_live, set_last_live) = signal(f32::NEG_INFINITY);
 
-                        // Normalize to 0.0-1.0
-                        image_array[y][x] = (pixel[0] as f32) / 255.0;
-                    }
-                }
+    let classify_live = move || {
+        let now = timestamp();
+        if live_interval_ms <= 0.0 || now - last_live.get_untracked() < live_interval_ms {
+            return;
+        }
+        set_last_live.set(now);
+        if let Some(client) = client_for_live.borrow().as_ref() {
+            client.classify(InferenceInput::Strokes(to_detexify(&strokes.get_untracked())), MAX_RESULTS);
+        }
+    };
 
-                // Run inference
-                match model_inner.borrow_mut().inference(image_array).await {
-                    predictions => {
-                        set_predictions.set(Some(predictions));
+    let on_pointer_move = move |e: web_sys::PointerEvent| {
+        if active_pointer.get_untracked() != Some(e.pointer_id()) || !drawing.get_untracked() {
+            return;
+        }
+        if let Some(canvas) = canvas_ref.get() {
+            e.prevent_default();
+            let samples: Vec<(f64, f64, f32)> = coalesced_events(&e)
+                .iter()
+                .map(|sample| {
+                    let (x, y) = get_pointer_coords(sample, &canvas);
+                    (x, y, sample.pressure())
+                })
+                .collect();
+            set_strokes.update(|strokes| {
+                if let Some(stroke) = strokes.last_mut() {
+                    for (x, y, pressure) in samples {
+                        stroke.push(x, y, pressure);
                     }
                 }
-                set_classifying.set(false);
             });
+            // Superseded by the pen-up classification if that comes first
+            classify_live();
         }
-    });
+    };
 
-    let on_mouse_down = move |e: web_sys::MouseEvent| {
-        set_drawing.set(true);
+    let on_pointer_up = move |e: web_sys::PointerEvent| {
+        if active_pointer.get_untracked() != Some(e.pointer_id()) {
+            return;
+        }
+        set_active_pointer.set(None);
         if let Some(canvas) = canvas_ref.get() {
-            let canvas: HtmlCanvasElement = canvas.clone().into();
-            let ctx = canvas
-                .get_context("2d")
-                .unwrap()
-                .unwrap()
-                .dyn_into::<CanvasRenderingContext2d>()
-                .unwrap();
-
-            let rect = canvas.get_bounding_client_rect();
-            let (x, y) = get_logical_coords(&e, &canvas);
-
-            ctx.begin_path();
-            ctx.move_to(x, y);
+            let _ = canvas.release_pointer_capture(e.pointer_id());
         }
+        finish_stroke();
     };
 
-    let on_mouse_move = move |e: web_sys::MouseEvent| {
-        if drawing.get() {
-            if let Some(canvas) = canvas_ref.get() {
-                let canvas: HtmlCanvasElement = canvas.clone().into();
-                let ctx = canvas
-                    .get_context("2d")
-                    .unwrap()
-                    .unwrap()
-                    .dyn_into::<CanvasRenderingContext2d>()
-                    .unwrap();
-
-                let (x, y) = get_logical_coords(&e, &canvas);
-
-                ctx.line_to(x, y);
-                ctx.stroke();
-            }
+    let clear_canvas = move |_| {
+        let current = strokes.get_untracked();
+        if current.is_empty() {
+            return;
         }
+        set_history.update(|history| history.record(current));
+        set_strokes.set(Vec::new());
+        edited();
     };
 
-    let perform_classification_clone2 = Rc::clone(&perform_classification);
-    let on_mouse_up = move |_| {
-        set_drawing.set(false);
-        // Final classification when done drawing
-        perform_classification_clone2();
-    };
+    // Rows are keyed by symbol, so a live update moves and refreshes them instead of rebuilding
+    let predicted = Memo::new(move |_| match prediction.get() {
+        Some(Classification::Recognised(predicted)) => predicted,
+        _ => Vec::new()
+    });
+    let (show_more, set_show_more) = signal(false);
 
-    // Touch event handlers
-    let on_touch_start = move |e: web_sys::TouchEvent| {
-        e.prevent_default();
-        set_drawing.set(true);
-        if let Some(canvas) = canvas_ref.get() {
-            let canvas: HtmlCanvasElement = canvas.clone().into();
-            let ctx = canvas
-                .get_context("2d")
-                .unwrap()
-                .unwrap()
-                .dyn_into::<CanvasRenderingContext2d>()
-                .unwrap();
-
-            if let Some((x, y)) = get_touch_coords(&e, &canvas) {
-                ctx.begin_path();
-                ctx.move_to(x, y);
+    Effect::new(move |previous: Option<()>| {
+        revision.get();
+        // Nothing has been edited on the first run
+        if previous.is_none() {
+            return;
+        }
+        let client = client.borrow();
+        let Some(client) = client.as_ref() else { return };
+        let current = strokes.get_untracked();
+        if current.is_empty() {
+            client.cancel();
+            set_model_input.set(None);
+            set_predictions.set(None);
+        } else {
+            // The worker only evaluates the newest request, so every edit is sent straight away
+            client.classify(InferenceInput::Strokes(to_detexify(&current)), MAX_RESULTS);
+        }
+    });
+
+    // Ctrl+Z / Ctrl+Shift+Z (Cmd on macOS), except while typing in a text field
+    Effect::new(move |_| {
+        let handle = window_event_listener(ev::keydown, move |e: web_sys::KeyboardEvent| {
+            if !(e.ctrl_key() || e.meta_key()) || !e.key().eq_ignore_ascii_case("z") {
+                return;
+            }
+            let typing = e.target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+                .is_some();
+            if typing {
+                return;
             }
+            e.prevent_default();
+            if e.shift_key() {
+                redo();
+            } else {
+                undo();
+            }
+        });
+        on_cleanup(move || handle.remove());
+    });
+
+    // Registers the current drawing as another example of the typed command
+    let add_custom_example = move |_| {
+        let command = custom_command.get();
+        if command.trim().is_empty() || strokes.get_untracked().is_empty() {
+            return;
+        }
+        if let Some(client) = client_for_custom.borrow().as_ref() {
+            let input = InferenceInput::Strokes(to_detexify(&strokes.get_untracked()));
+            client.embed(input, move |embedding| {
+                set_custom_symbols.update(|library| {
+                    if library.add_example(&command, embedding) {
+                        set_custom_message.set(None);
+                        save_library(library);
+                    } else {
+                        set_custom_message.set(Some(format!("{command} already has the maximum of {MAX_EXAMPLES} examples")));
+                    }
+                });
+            });
         }
     };
 
-    let on_touch_move = move |e: web_sys::TouchEvent| {
-        e.prevent_default();
-        if drawing.get() {
-            if let Some(canvas) = canvas_ref.get() {
-                let canvas: HtmlCanvasElement = canvas.clone().into();
-                let ctx = canvas
-                    .get_context("2d")
-                    .unwrap()
-                    .unwrap()
-                    .dyn_into::<CanvasRenderingContext2d>()
-                    .unwrap();
-
-                if let Some((x, y)) = get_touch_coords(&e, &canvas) {
-                    ctx.line_to(x, y);
-                    ctx.stroke();
+    let export_custom_symbols = move |_| {
+        export_library(&custom_symbols.get_untracked());
+    };
+
+    let import_custom_symbols = move |e: web_sys::Event| {
+        let file = e.target()
+            .and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0));
+        if let Some(file) = file {
+            wasm_bindgen_futures::spawn_local(async move {
+                match import_library(file).await {
+                    Ok(library) => {
+                        save_library(&library);
+                        set_custom_symbols.set(library);
+                        set_custom_message.set(None);
+                    }
+                    Err(message) => set_custom_message.set(Some(message))
                 }
-            }
+            });
         }
     };
 
-    let perform_classification_clone3 = Rc::clone(&perform_classification);
-    let on_touch_end = move |e: web_sys::TouchEvent| {
-        e.prevent_default();
-        set_drawing.set(false);
-        // Final classification when done drawing
-        perform_classification_clone3();
-    };
+    // Re-render the canvas whenever the strokes change
+    Effect::new(move |_| {
+        let strokes = strokes.get();
+        if let Some(canvas) = canvas_ref.get() {
+            render_strokes(&canvas, &strokes);
+        }
+    });
 
-    let clear_canvas = move |_| {
+    // Keep the backing store at device resolution as the layout or zoom level changes
+    Effect::new(move |_| {
         if let Some(canvas) = canvas_ref.get() {
-            let canvas: HtmlCanvasElement = canvas.clone().into();
-            let ctx = canvas
-                .get_context("2d")
-                .unwrap()
-                .unwrap()
-                .dyn_into::<CanvasRenderingContext2d>()
-                .unwrap();
-
-            ctx.clear_rect(0.0, 0.0, canvas.width() as f64, canvas.height() as f64);
+            fit_to_container(&canvas);
+            render_strokes(&canvas, &strokes.get_untracked());
         }
-        set_predictions.set(None);
-    };
+        let handle = window_event_listener(ev::resize, move |_| {
+            if let Some(canvas) = canvas_ref.get_untracked() {
+                fit_to_container(&canvas);
+                render_strokes(&canvas, &strokes.get_untracked());
+            }
+        });
+        on_cleanup(move || handle.remove());
+    });
+
+    Effect::new(move |_| {
+        let image = model_input.get();
+        if !show_model_input.get() {
+            return;
+        }
+        if let Some(canvas) = processed_canvas_ref.get() {
+            render_model_input(&canvas, &image.unwrap_or([[0.0; WIDTH]; HEIGHT]));
+        }
+    });
 
     Effect::new(move |_| {
-        setup_canvas();
+        *client_for_mount.borrow_mut() = Some(InferenceClient::new(move |response| match response {
+            WorkerResponse::Ready { backend } => set_backend.set(Some(backend)),
+            WorkerResponse::Classified { image, classification, latency_ms, .. } => {
+                set_model_input.set(Some(*image));
+                set_predictions.set(Some(classification));
+                set_latency.set(Some(latency_ms));
+            }
+            WorkerResponse::Embedded { .. } => {}
+        }));
+        set_custom_symbols.set(load_library());
+    });
+
+    // Keep the worker's copy of the custom symbols in step with the page
+    Effect::new(move |_| {
+        let library = custom_symbols.get();
+        if let Some(client) = client_for_symbols.borrow().as_ref() {
+            client.set_custom_symbols(&library);
+        }
     });
 
     view! {
@@ -243,20 +402,82 @@ pub fn Classifier() -> impl IntoView {
             <div class="canvas-section">
                 <h3>Draw Here</h3>
                 <canvas
+                    class="drawing-canvas"
                     node_ref=canvas_ref
-                    width="32"
-                    height="32"
-                    on:mousedown=on_mouse_down
-                    on:mousemove=on_mouse_move
-                    on:mouseup=on_mouse_up.clone()
-                    on:mouseleave=on_mouse_up
-                    on:touchstart=on_touch_start
-                    on:touchmove=on_touch_move
-                    on:touchend=on_touch_end.clone()
-                    on:touchcancel=on_touch_end
+                    on:pointerdown=on_pointer_down
+                    on:pointermove=on_pointer_move
+                    on:pointerup=on_pointer_up
+                    on:pointercancel=on_pointer_up
                 ></canvas>
                 <div class="button-row">
+                    <button on:click=move |_| undo() disabled=move || !history.get().can_undo()>Undo</button>
+                    <button on:click=move |_| redo() disabled=move || !history.get().can_redo()>Redo</button>
+                    <button
+                        class:active=move || erasing.get()
+                        on:click=move |_| set_erasing.set(!erasing.get_untracked())
+                    >Eraser</button>
                     <button on:click=clear_canvas>Clear</button>
+                    <button on:click=move |_| set_show_model_input.set(!show_model_input.get_untracked())>
+                        {move || if show_model_input.get() { "Hide Model Input" } else { "Show Model Input" }}
+                    </button>
+                </div>
+                <Show when=move || show_model_input.get()>
+                    <div class="model-input">
+                        <p>{format!("What the model sees ({WIDTH}x{HEIGHT})")}</p>
+                        <canvas
+                            class="processed-canvas"
+                            node_ref=processed_canvas_ref
+                            width=WIDTH.to_string()
+                            height=HEIGHT.to_string()
+                        ></canvas>
+                    </div>
+                </Show>
+                <div class="diagnostics">
+                    <span>
+                        "Backend: "
+                        {move || backend.get().map(|backend| backend.to_string()).unwrap_or_else(|| "loading…".to_string())}
+                    </span>
+                    <span>
+                        "Latency: "
+                        {move || latency.get().map(|latency| format!("{latency:.1} ms")).unwrap_or_else(|| "–".to_string())}
+                    </span>
+                </div>
+                <div class="custom-symbols">
+                    <h3>Custom Symbols</h3>
+                    <div class="button-row">
+                        <input
+                            type="text"
+                            placeholder="\\Zc"
+                            prop:value=custom_command
+                            on:input=move |e| set_custom_command.set(event_target_value(&e))
+                        />
+                        <button on:click=add_custom_example>Add Example</button>
+                    </div>
+                    {move || custom_message.get().map(|message| view! { <p class="custom-message">{message}</p> })}
+                    <For
+                        each=move || custom_symbols.get().symbols
+                        key=|symbol| (symbol.command.clone(), symbol.examples.len())
+                        children=move |symbol| {
+                            let command = symbol.command.clone();
+                            view! {
+                                <div class="custom-symbol">
+                                    <span><strong>{symbol.command.clone()}</strong></span>
+                                    <span>{format!("{} examples (min {})", symbol.examples.len(), MIN_EXAMPLES)}</span>
+                                    <button on:click=move |_| set_custom_symbols.update(|library| {
+                                        library.remove(&command);
+                                        save_library(library);
+                                    })>Remove</button>
+                                </div>
+                            }
+                        }
+                    />
+                    <div class="button-row">
+                        <button on:click=export_custom_symbols>Export</button>
+                        <label class="import-button">
+                            "Import"
+                            <input type="file" accept="application/json" style="display: none;" on:change=import_custom_symbols/>
+                        </label>
+                    </div>
                 </div>
             </div>
                 
@@ -265,126 +486,109 @@ pub fn Classifier() -> impl IntoView {
                     when=move || prediction.get().is_some()
                     fallback=|| view! { <p>Predictions will appear here!</p> }
                 >
-                    {move || {
-                        let predicted = prediction.get().unwrap().clone();
-                        view! {
-                            <For
-                                each=move || predicted.clone()
-                                key=|state| state.clone()
-                                children=move |child| {
-                                    view! {
-                                        <PredictionItem prediction=child/>
-                                    }
+                    <Show
+                        when=move || !matches!(prediction.get(), Some(Classification::NotRecognised))
+                        fallback=|| view! { <p>"Symbol not recognised, try drawing it again."</p> }
+                    >
+                        <For
+                            each=move || {
+                                let count = if show_more.get() { MAX_RESULTS } else { VISIBLE_RESULTS };
+                                predicted.get().into_iter().take(count).collect::<Vec<_>>()
+                            }
+                            key=|prediction| prediction.key.clone()
+                            children=move |child| {
+                                let symbol = child.key.clone();
+                                let probability = Signal::derive(move || {
+                                    predicted.get()
+                                        .iter()
+                                        .find(|prediction| prediction.key == symbol)
+                                        .map(|prediction| prediction.probability)
+                                        .unwrap_or_default()
+                                });
+                                view! {
+                                    <PredictionItem symbol=child.key probability=probability/>
                                 }
-                            />
-                        }
-                    }}
+                            }
+                        />
+                        <Show when=move || { predicted.get().len() > VISIBLE_RESULTS }>
+                            <button class="show-more" on:click=move |_| set_show_more.set(!show_more.get_untracked())>
+                                {move || if show_more.get() {
+                                    "Show fewer".to_string()
+                                } else {
+                                    format!("Show more ({})", predicted.get().len() - VISIBLE_RESULTS)
+                                }}
+                            </button>
+                        </Show>
+                    </Show>
                 </Show>
             </div>
+            <Show when=move || toast.get().is_some()>
+                <div class="toast" role="status">{move || toast.get().map(|(_, message)| message)}</div>
+            </Show>
         </div>
     }
 }
 
 #[component]
-fn PredictionItem(prediction: Prediction) -> impl IntoView {
-    // Sanitize URL same way as render_images.py does
-    let safe_symbol = prediction.symbol.replace("/", "_slash_").replace("\\", "_backslash_");
-    let url = format!("/symbols/{}.png", safe_symbol);
-    // Parse: "package-encoding-_symbol" -> extract package and symbol
-    // Example: "latex2e-OT1-_alpha" -> package="latex2e", symbol="alpha"
-    let split = prediction.symbol.clone().split("_").map(|it| it.to_string()).collect::<Vec<String>>();
-    let symbol = split.get(1).unwrap_or(&split[0]).clone();
-    let package = split.get(0).map(|s| s.split("-").next().unwrap_or("").to_string()).unwrap_or_default();
+fn CopyButton(label: String, text: String) -> impl IntoView {
+    let toast = use_context::<Toast>();
+    let title = format!("Copy {text}");
+    let copy = move |_| {
+        let text = text.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let message = if copy_text(&text).await {
+                format!("Copied {text}")
+            } else {
+                "Couldn't access the clipboard".to_string()
+            };
+            if let Some(toast) = toast {
+                toast.show(message);
+            }
+        });
+    };
+
+    view! {
+        <button class="copy-button" title=title on:click=copy>{label}</button>
+    }
+}
+
+#[component]
+fn PredictionItem(symbol: String, probability: Signal<f32>) -> impl IntoView {
+    // User-defined symbols have no package or rendered glyph
+    if let Some(command) = symbol.strip_prefix(CUSTOM_KEY_PREFIX) {
+        let command = command.to_string();
+        return view! {
+            <div class="prediction-item">
+                <div style="display: flex; align-items: center; flex-direction: column;">
+                    <p><em>"Custom symbol"</em></p>
+                    <p><strong>"\\"{command.clone()}</strong></p>
+                    <p>{move || format!("{:.2}%", probability.get() * 100.0)}</p>
+                    <div class="copy-buttons">
+                        <CopyButton label="Command".to_string() text=format!("\\{command}")/>
+                    </div>
+                </div>
+            </div>
+        }.into_any();
+    }
+
+    let key = symbol;
+    let entry = SymbolCatalogue::embedded().get(&key);
+    let command = entry.map(|entry| entry.command.clone()).unwrap_or_else(|| key.clone());
+    let wrapped = entry.map(|entry| entry.wrapped()).unwrap_or_else(|| command.clone());
+    let preamble = entry.map(|entry| entry.preamble().join("\n")).unwrap_or_default();
     view! {
         <div class="prediction-item">
             <div style="display: flex; align-items: center; flex-direction: column;">
-                <p><strong>"\\usepackage{"{package.clone()}"}"</strong></p>
-                <p><strong>"\\"{symbol.clone()}</strong></p>
-                <p>{format!("{:.2}%", prediction.probability)}</p>
+                {(!preamble.is_empty()).then(|| view! { <p class="preamble"><strong>{preamble.clone()}</strong></p> })}
+                <p><a href=symbol_path(&key) title="Symbol details"><strong>{command.clone()}</strong></a></p>
+                <p>{move || format!("{:.2}%", probability.get() * 100.0)}</p>
+                <div class="copy-buttons">
+                    <CopyButton label="Command".to_string() text=command.clone()/>
+                    {(wrapped != command).then(|| view! { <CopyButton label=wrapped.clone() text=wrapped.clone()/> })}
+                    {(!preamble.is_empty()).then(|| view! { <CopyButton label="Preamble".to_string() text=preamble.clone()/> })}
+                </div>
             </div>
-            {
-                // Packages NOT supported by MathJax - always use PNG
-                let unsupported_packages = [
-                    "marvosym",   // Zodiac symbols, special symbols - not in MathJax
-                    "wasysym",    // Astronomy symbols, special symbols - not in MathJax
-                    "tipa",       // Phonetic alphabet - not in MathJax
-                    "skull",      // Skull symbol - not in MathJax
-                    "bbold",      // Blackboard bold variants - limited support
-                    "cmll",       // Linear logic symbols - not in MathJax
-                    "dsfont",     // Double-struck fonts - not in MathJax
-                    "mathdots",   // Special dots - limited support
-                    "mathrsfs",   // Script fonts - limited support
-                    "upgreek",    // Upright Greek - limited support
-                ];
-
-                // Packages with PARTIAL support - use PNG when in doubt
-                let partial_support_packages = [
-                    "textcomp",   // Text companion symbols - many not supported
-                    "gensymb",    // Generic symbols like degree, celsius - not supported
-                    "latexsym",   // Legacy symbols - some supported
-                    "stmaryrd",   // St Mary Road symbols - not supported
-                    "esint",      // Extended integrals - limited support
-                ];
-
-                // Text-mode symbols from latex2e that need PNG (MathJax text support is limited)
-                let text_symbols_needing_png = [
-                    // T1 encoding symbols
-                    "DH", "DJ", "NG", "TH", "dh", "dj", "ng", "th",
-                    "guillemotleft", "guillemotright", "guilsinglleft", "guilsinglright",
-                    "quotedblbase", "quotesinglbase", "textquotedbl",
-
-                    // Special text symbols with poor MathJax support
-                    "textexclamdown", "textquestiondown", "textordfeminine", "textordmasculine",
-                    "textregistered", "textcopyright", "texttrademark",
-                    "textsterling", "textcent", "textdollar",
-                    "textbackslash", "textbar", "textbraceleft", "textbraceright",
-                    "textgreater", "textless", "textasciicircum", "textasciitilde",
-                    "textunderscore", "textvisiblespace",
-
-                    // OT1 text symbols
-                    "AA", "aa", "AE", "ae", "OE", "oe", "O", "o", "L", "l", "SS", "ss",
-                    "textasteriskcentered", "textbullet", "textperiodcentered",
-                    "textdagger", "textdaggerdbl", "textparagraph", "textsection",
-                    "textellipsis", "textemdash", "textendash",
-                    "textquotedblleft", "textquotedblright", "textquoteleft", "textquoteright",
-                ];
-
-                // Math symbols from packages with known issues
-                let math_symbols_needing_png = [
-                    // amssymb symbols with rendering issues
-                    "mathfrak{", "mathcal{", "mathscr{", "mathds{", "mathbb{",
-
-                    // Special brackets
-                    "llbracket", "rrbracket", "llceil", "rrceil", "llfloor", "rrfloor",
-                    "llparenthesis", "rrparenthesis",
-                ];
-
-                // Check if we should use PNG
-                let use_png = unsupported_packages.contains(&package.as_str())
-                    || partial_support_packages.contains(&package.as_str())
-                    || (
-                        // Only check text/math symbols for non-standard packages
-                        // latex2e and amsmath/amssymb have great MathJax support
-                        !["latex2e", "amsmath", "amssymb"].contains(&package.as_str()) &&
-                        (text_symbols_needing_png.iter().any(|s| symbol.contains(s))
-                        || math_symbols_needing_png.iter().any(|s| symbol.contains(s)))
-                    )
-                    // If symbol starts with "text", assume PNG to be safe (except basic ones)
-                    || (symbol.starts_with("text") && !["textit", "textbf", "textrm", "text"].contains(&symbol.as_str()));
-
-                if use_png {
-                    view! {
-                        <div>
-                            <img src={url.clone()} style="width: 35px; height: 35px;" />
-                        </div>
-                    }.into_any()
-                } else {
-                    let latex_content = format!(r#"$\{symbol}$"#, symbol=symbol);
-                    view! {
-                        <div inner_html={format!(r#"<math-jax style="font-size: 35px;">{}</math-jax>"#, latex_content)}></div>
-                    }.into_any()
-                }
-            }
+            <SymbolGlyph symbol=key/>
         </div>
-    }
+    }.into_any()
 }
\ No newline at end of file
@@ -0,0 +1,103 @@
+use crate::app::classifier::protocol::{InferenceInput, WorkerRequest, WorkerResponse};
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use shared::custom_symbols::CustomSymbolLibrary;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::{MessageEvent, Worker, WorkerOptions, WorkerType};
+
+const WORKER_URL: &str = "/inference-worker.js";
+
+type EmbedCallback = Box<dyn FnOnce(Vec<f32>)>;
+
+/// The page's handle on the inference worker.
+pub struct InferenceClient {
+    worker: Worker,
+    next_id: Cell<u64>,
+    latest_classify: Rc<Cell<u64>>,
+    embed_callbacks: Rc<RefCell<HashMap<u64, EmbedCallback>>>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>
+}
+
+impl InferenceClient {
+    /// Starts the worker. `on_response` gets `Ready` and the result of the newest `classify` call;
+    /// stale classifications are dropped and embeddings go to their `embed` callbacks.
+    pub fn new(on_response: impl Fn(WorkerResponse) + 'static) -> Self {
+        let options = WorkerOptions::new();
+        options.set_type(WorkerType::Module);
+        let worker = Worker::new_with_options(WORKER_URL, &options)
+            .expect("Inference worker should start");
+
+        let latest_classify = Rc::new(Cell::new(0));
+        let embed_callbacks: Rc<RefCell<HashMap<u64, EmbedCallback>>> = Rc::default();
+
+        let latest = Rc::clone(&latest_classify);
+        let callbacks = Rc::clone(&embed_callbacks);
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(json) = event.data().as_string() else { return };
+            let Ok(response) = serde_json::from_str::<WorkerResponse>(&json) else { return };
+
+            match response {
+                // A newer drawing is already on its way, so this result is stale
+                WorkerResponse::Classified { id, .. } if id != latest.get() => {}
+                WorkerResponse::Embedded { id, embedding } => {
+                    let callback = callbacks.borrow_mut().remove(&id);
+                    if let Some(callback) = callback {
+                        callback(embedding);
+                    }
+                }
+                response => on_response(response)
+            }
+        });
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        Self {
+            worker,
+            next_id: Cell::new(1),
+            latest_classify,
+            embed_callbacks,
+            _on_message: on_message
+        }
+    }
+
+    fn send(&self, request: &WorkerRequest) {
+        let json = serde_json::to_string(request).expect("Worker requests should serialize");
+        let _ = self.worker.post_message(&JsValue::from_str(&json));
+    }
+
+    fn next_id(&self) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        id
+    }
+
+    /// Queues a classification, superseding any that haven't been answered yet.
+    pub fn classify(&self, input: InferenceInput, k: usize) {
+        let id = self.next_id();
+        self.latest_classify.set(id);
+        self.send(&WorkerRequest::Classify { id, input, k });
+    }
+
+    /// Discards the result of any classification still in flight.
+    pub fn cancel(&self) {
+        let id = self.next_id();
+        self.latest_classify.set(id);
+    }
+
+    pub fn embed(&self, input: InferenceInput, callback: impl FnOnce(Vec<f32>) + 'static) {
+        let id = self.next_id();
+        self.embed_callbacks.borrow_mut().insert(id, Box::new(callback));
+        self.send(&WorkerRequest::Embed { id, input });
+    }
+
+    pub fn set_custom_symbols(&self, library: &CustomSymbolLibrary) {
+        self.send(&WorkerRequest::SetCustomSymbols(library.clone()));
+    }
+}
+
+impl Drop for InferenceClient {
+    fn drop(&mut self) {
+        self.worker.terminate();
+    }
+}
@@ -0,0 +1,9 @@
+use wasm_bindgen_futures::JsFuture;
+
+/// Writes `text` to the system clipboard, returning whether the browser allowed it.
+pub async fn copy_text(text: &str) -> bool {
+    let Some(window) = web_sys::window() else { return false };
+    let promise = window.navigator().clipboard().write_text(text);
+
+    JsFuture::from(promise).await.is_ok()
+}
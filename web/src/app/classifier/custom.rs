@@ -0,0 +1,67 @@
+use crate::app::classifier::state::model_id;
+use burn::config::{config_to_json, Config};
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use shared::custom_symbols::CustomSymbolLibrary;
+use shared::model::HIDDEN_SIZE;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, File, HtmlAnchorElement, Storage, Url};
+
+const STORAGE_KEY: &str = "texify-custom-symbols";
+const EXPORT_FILENAME: &str = "texify-custom-symbols.json";
+
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Reads the user's custom symbols from browser storage, or an empty library for the current model.
+pub fn load_library() -> CustomSymbolLibrary {
+    local_storage()
+        .and_then(|storage| storage.get_item(STORAGE_KEY).ok()?)
+        .and_then(|json| CustomSymbolLibrary::load_binary(json.as_bytes()).ok())
+        .filter(|library| library.is_compatible(model_id(), HIDDEN_SIZE))
+        .unwrap_or_else(|| CustomSymbolLibrary::new().with_model(Some(model_id().to_string())))
+}
+
+pub fn save_library(library: &CustomSymbolLibrary) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(STORAGE_KEY, &config_to_json(library));
+    }
+}
+
+/// Downloads the library as a JSON file via a temporary object URL.
+pub fn export_library(library: &CustomSymbolLibrary) {
+    let parts = js_sys::Array::of1(&JsValue::from_str(&config_to_json(library)));
+    let options = BlobPropertyBag::new();
+    options.set_type("application/json");
+
+    let Ok(blob) = Blob::new_with_str_sequence_and_options(&parts, &options) else { return };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else { return };
+
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok());
+    if let Some(anchor) = anchor {
+        anchor.set_href(&url);
+        anchor.set_download(EXPORT_FILENAME);
+        anchor.click();
+    }
+
+    let _ = Url::revoke_object_url(&url);
+}
+
+/// Reads an exported library, rejecting files whose embeddings don't fit the current model.
+pub async fn import_library(file: File) -> Result<CustomSymbolLibrary, String> {
+    let text = JsFuture::from(file.text()).await
+        .ok()
+        .and_then(|text| text.as_string())
+        .ok_or("The file could not be read")?;
+    let library = CustomSymbolLibrary::load_binary(text.as_bytes())
+        .map_err(|_| "The file is not an exported custom symbol library")?;
+
+    if !library.is_compatible(model_id(), HIDDEN_SIZE) {
+        return Err("The library was recorded with a different model; draw its examples again".to_string());
+    }
+
+    Ok(library)
+}
@@ -0,0 +1,73 @@
+use crate::app::classifier::model::{Classification, SharedModel};
+use crate::app::classifier::protocol::InferenceBackend;
+use crate::app::classifier::state::{CpuBackend, GpuBackend};
+use burn::backend::ndarray::NdArrayDevice;
+use burn::backend::wgpu::graphics::WebGpu;
+use burn::backend::wgpu::{init_setup_async, RuntimeOptions, WgpuDevice};
+use js_sys::{Function, Promise, Reflect};
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use shared::custom_symbols::CustomSymbolLibrary;
+use shared::item::{HEIGHT, WIDTH};
+use wasm_bindgen_futures::JsFuture;
+
+/// The model on whichever backend the browser supports, boxed as the backends differ in size.
+pub enum Engine {
+    Gpu(Box<SharedModel<GpuBackend>>),
+    Cpu(Box<SharedModel<CpuBackend>>)
+}
+
+/// Whether `navigator.gpu` exists and hands out an adapter; some browsers expose the API without one.
+async fn webgpu_available() -> bool {
+    let Ok(navigator) = Reflect::get(&js_sys::global(), &JsValue::from_str("navigator")) else { return false };
+    let Ok(gpu) = Reflect::get(&navigator, &JsValue::from_str("gpu")) else { return false };
+    if gpu.is_undefined() || gpu.is_null() {
+        return false;
+    }
+    let request_adapter = Reflect::get(&gpu, &JsValue::from_str("requestAdapter"))
+        .ok()
+        .and_then(|function| function.dyn_into::<Function>().ok());
+    let Some(request_adapter) = request_adapter else { return false };
+    let Some(promise) = request_adapter.call0(&gpu).ok().and_then(|value| value.dyn_into::<Promise>().ok()) else {
+        return false;
+    };
+
+    match JsFuture::from(promise).await {
+        Ok(adapter) => !adapter.is_null() && !adapter.is_undefined(),
+        Err(_) => false
+    }
+}
+
+impl Engine {
+    /// Runs on WebGPU when available, falling back to the NdArray CPU backend.
+    pub async fn detect() -> Self {
+        if webgpu_available().await {
+            let device = WgpuDevice::default();
+            // Wgpu can't block on adapter setup in the browser, so it has to be initialised up front
+            init_setup_async::<WebGpu>(&device, RuntimeOptions::default()).await;
+            Engine::Gpu(Box::new(SharedModel::new(device)))
+        } else {
+            Engine::Cpu(Box::new(SharedModel::new(NdArrayDevice::default())))
+        }
+    }
+
+    pub fn backend(&self) -> InferenceBackend {
+        match self {
+            Engine::Gpu(_) => InferenceBackend::WebGpu,
+            Engine::Cpu(_) => InferenceBackend::Cpu
+        }
+    }
+
+    pub async fn embed(&mut self, image: [[f32; WIDTH]; HEIGHT]) -> Vec<f32> {
+        match self {
+            Engine::Gpu(model) => model.embed(image).await,
+            Engine::Cpu(model) => model.embed(image).await
+        }
+    }
+
+    pub async fn inference(&mut self, image: [[f32; WIDTH]; HEIGHT], custom_symbols: &CustomSymbolLibrary, k: usize) -> Classification {
+        match self {
+            Engine::Gpu(model) => model.inference(image, custom_symbols, k).await,
+            Engine::Cpu(model) => model.inference(image, custom_symbols, k).await
+        }
+    }
+}
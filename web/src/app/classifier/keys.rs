@@ -1,2 +0,0 @@
-pub static KEYS: [&str; 1098] = ["amsmath-OT1-_dotsb", "amsmath-OT1-_dotsc", "amsmath-OT1-_dotsi", "amsmath-OT1-_dotsm", "amsmath-OT1-_dotso", "amsmath-OT1-_idotsint", "amsmath-OT1-_iiiint", "amsmath-OT1-_iiint", "amsmath-OT1-_iint", "amssymb-OT1-_Bbbk", "amssymb-OT1-_Bumpeq", "amssymb-OT1-_Cap", "amssymb-OT1-_Cup", "amssymb-OT1-_Finv", "amssymb-OT1-_Game", "amssymb-OT1-_Lleftarrow", "amssymb-OT1-_Lsh", "amssymb-OT1-_Rrightarrow", "amssymb-OT1-_Rsh", "amssymb-OT1-_Subset", "amssymb-OT1-_Supset", "amssymb-OT1-_Vdash", "amssymb-OT1-_Vvdash", "amssymb-OT1-_angle", "amssymb-OT1-_approxeq", "amssymb-OT1-_backepsilon", "amssymb-OT1-_backprime", "amssymb-OT1-_backsim", "amssymb-OT1-_backsimeq", "amssymb-OT1-_barwedge", "amssymb-OT1-_because", "amssymb-OT1-_between", "amssymb-OT1-_bigstar", "amssymb-OT1-_blacklozenge", "amssymb-OT1-_blacksquare", "amssymb-OT1-_blacktriangle", "amssymb-OT1-_blacktriangledown", "amssymb-OT1-_blacktriangleleft", "amssymb-OT1-_blacktriangleright", "amssymb-OT1-_boxdot", "amssymb-OT1-_boxminus", "amssymb-OT1-_boxplus", "amssymb-OT1-_boxtimes", "amssymb-OT1-_bumpeq", "amssymb-OT1-_centerdot", "amssymb-OT1-_checkmark", "amssymb-OT1-_circeq", "amssymb-OT1-_circlearrowleft", "amssymb-OT1-_circlearrowright", "amssymb-OT1-_circledR", "amssymb-OT1-_circledS", "amssymb-OT1-_circledast", "amssymb-OT1-_circledcirc", "amssymb-OT1-_circleddash", "amssymb-OT1-_complement", "amssymb-OT1-_curlyeqprec", "amssymb-OT1-_curlyeqsucc", "amssymb-OT1-_curlyvee", "amssymb-OT1-_curlywedge", "amssymb-OT1-_curvearrowleft", "amssymb-OT1-_curvearrowright", "amssymb-OT1-_dashleftarrow", "amssymb-OT1-_dashrightarrow", "amssymb-OT1-_diagdown", "amssymb-OT1-_diagup", "amssymb-OT1-_digamma", "amssymb-OT1-_divideontimes", "amssymb-OT1-_doteqdot", "amssymb-OT1-_dotplus", "amssymb-OT1-_doublebarwedge", "amssymb-OT1-_downdownarrows", "amssymb-OT1-_downharpoonleft", "amssymb-OT1-_downharpoonright", "amssymb-OT1-_eqcirc", "amssymb-OT1-_eqslantgtr", "amssymb-OT1-_eqslantless", "amssymb-OT1-_eth", "amssymb-OT1-_fallingdotseq", "amssymb-OT1-_geq", "amssymb-OT1-_geqq", "amssymb-OT1-_geqslant", "amssymb-OT1-_gg", "amssymb-OT1-_ggg", "amssymb-OT1-_gnapprox", "amssymb-OT1-_gneq", "amssymb-OT1-_gneqq", "amssymb-OT1-_gnsim", "amssymb-OT1-_gtrapprox", "amssymb-OT1-_gtrdot", "amssymb-OT1-_gtreqless", "amssymb-OT1-_gtreqqless", "amssymb-OT1-_gtrless", "amssymb-OT1-_gtrsim", "amssymb-OT1-_gvertneqq", "amssymb-OT1-_hbar", "amssymb-OT1-_hslash", "amssymb-OT1-_intercal", "amssymb-OT1-_leftarrowtail", "amssymb-OT1-_leftleftarrows", "amssymb-OT1-_leftrightarrows", "amssymb-OT1-_leftrightharpoons", "amssymb-OT1-_leftrightsquigarrow", "amssymb-OT1-_leftthreetimes", "amssymb-OT1-_leq", "amssymb-OT1-_leqq", "amssymb-OT1-_leqslant", "amssymb-OT1-_lessapprox", "amssymb-OT1-_lessdot", "amssymb-OT1-_lesseqgtr", "amssymb-OT1-_lesseqqgtr", "amssymb-OT1-_lessgtr", "amssymb-OT1-_lesssim", "amssymb-OT1-_ll", "amssymb-OT1-_lll", "amssymb-OT1-_lnapprox", "amssymb-OT1-_lneq", "amssymb-OT1-_lneqq", "amssymb-OT1-_lnsim", "amssymb-OT1-_looparrowleft", "amssymb-OT1-_looparrowright", "amssymb-OT1-_lozenge", "amssymb-OT1-_ltimes", "amssymb-OT1-_lvertneqq", "amssymb-OT1-_maltese", "amssymb-OT1-_mathcal{A}", "amssymb-OT1-_mathcal{B}", "amssymb-OT1-_mathcal{C}", "amssymb-OT1-_mathcal{D}", "amssymb-OT1-_mathcal{E}", "amssymb-OT1-_mathcal{F}", "amssymb-OT1-_mathcal{G}", "amssymb-OT1-_mathcal{H}", "amssymb-OT1-_mathcal{I}", "amssymb-OT1-_mathcal{J}", "amssymb-OT1-_mathcal{K}", "amssymb-OT1-_mathcal{L}", "amssymb-OT1-_mathcal{M}", "amssymb-OT1-_mathcal{N}", "amssymb-OT1-_mathcal{O}", "amssymb-OT1-_mathcal{P}", "amssymb-OT1-_mathcal{Q}", "amssymb-OT1-_mathcal{R}", "amssymb-OT1-_mathcal{S}", "amssymb-OT1-_mathcal{T}", "amssymb-OT1-_mathcal{U}", "amssymb-OT1-_mathcal{V}", "amssymb-OT1-_mathcal{W}", "amssymb-OT1-_mathcal{X}", "amssymb-OT1-_mathcal{Y}", "amssymb-OT1-_mathcal{Z}", "amssymb-OT1-_mathfrak{A}", "amssymb-OT1-_mathfrak{B}", "amssymb-OT1-_mathfrak{C}", "amssymb-OT1-_mathfrak{D}", "amssymb-OT1-_mathfrak{E}", "amssymb-OT1-_mathfrak{F}", "amssymb-OT1-_mathfrak{G}", "amssymb-OT1-_mathfrak{H}", "amssymb-OT1-_mathfrak{I}", "amssymb-OT1-_mathfrak{J}", "amssymb-OT1-_mathfrak{K}", "amssymb-OT1-_mathfrak{L}", "amssymb-OT1-_mathfrak{M}", "amssymb-OT1-_mathfrak{N}", "amssymb-OT1-_mathfrak{O}", "amssymb-OT1-_mathfrak{P}", "amssymb-OT1-_mathfrak{Q}", "amssymb-OT1-_mathfrak{R}", "amssymb-OT1-_mathfrak{S}", "amssymb-OT1-_mathfrak{T}", "amssymb-OT1-_mathfrak{U}", "amssymb-OT1-_mathfrak{V}", "amssymb-OT1-_mathfrak{W}", "amssymb-OT1-_mathfrak{X}", "amssymb-OT1-_mathfrak{Y}", "amssymb-OT1-_mathfrak{Z}", "amssymb-OT1-_mathfrak{a}", "amssymb-OT1-_mathfrak{b}", "amssymb-OT1-_mathfrak{c}", "amssymb-OT1-_mathfrak{d}", "amssymb-OT1-_mathfrak{e}", "amssymb-OT1-_mathfrak{f}", "amssymb-OT1-_mathfrak{g}", "amssymb-OT1-_mathfrak{h}", "amssymb-OT1-_mathfrak{i}", "amssymb-OT1-_mathfrak{j}", "amssymb-OT1-_mathfrak{k}", "amssymb-OT1-_mathfrak{l}", "amssymb-OT1-_mathfrak{m}", "amssymb-OT1-_mathfrak{n}", "amssymb-OT1-_mathfrak{o}", "amssymb-OT1-_mathfrak{p}", "amssymb-OT1-_mathfrak{q}", "amssymb-OT1-_mathfrak{r}", "amssymb-OT1-_mathfrak{s}", "amssymb-OT1-_mathfrak{t}", "amssymb-OT1-_mathfrak{u}", "amssymb-OT1-_mathfrak{v}", "amssymb-OT1-_mathfrak{w}", "amssymb-OT1-_mathfrak{x}", "amssymb-OT1-_mathfrak{y}", "amssymb-OT1-_mathfrak{z}", "amssymb-OT1-_measuredangle", "amssymb-OT1-_mho", "amssymb-OT1-_multimap", "amssymb-OT1-_nLeftarrow", "amssymb-OT1-_nLeftrightarrow", "amssymb-OT1-_nRightarrow", "amssymb-OT1-_nVDash", "amssymb-OT1-_ncong", "amssymb-OT1-_neq", "amssymb-OT1-_nexists", "amssymb-OT1-_ngeq", "amssymb-OT1-_ngeqq", "amssymb-OT1-_ngeqslant", "amssymb-OT1-_ngtr", "amssymb-OT1-_nleftarrow", "amssymb-OT1-_nleftrightarrow", "amssymb-OT1-_nleq", "amssymb-OT1-_nleqq", "amssymb-OT1-_nleqslant", "amssymb-OT1-_nless", "amssymb-OT1-_nmid", "amssymb-OT1-_nparallel", "amssymb-OT1-_nprec", "amssymb-OT1-_npreceq", "amssymb-OT1-_nrightarrow", "amssymb-OT1-_nshortmid", "amssymb-OT1-_nshortparallel", "amssymb-OT1-_nsim", "amssymb-OT1-_nsubseteq", "amssymb-OT1-_nsucc", "amssymb-OT1-_nsucceq", "amssymb-OT1-_nsupseteq", "amssymb-OT1-_nsupseteqq", "amssymb-OT1-_ntriangleleft", "amssymb-OT1-_ntrianglelefteq", "amssymb-OT1-_ntriangleright", "amssymb-OT1-_ntrianglerighteq", "amssymb-OT1-_nvDash", "amssymb-OT1-_nvdash", "amssymb-OT1-_pitchfork", "amssymb-OT1-_precapprox", "amssymb-OT1-_preccurlyeq", "amssymb-OT1-_precnapprox", "amssymb-OT1-_precnsim", "amssymb-OT1-_precsim", "amssymb-OT1-_rightarrowtail", "amssymb-OT1-_rightleftarrows", "amssymb-OT1-_rightleftharpoons", "amssymb-OT1-_rightrightarrows", "amssymb-OT1-_rightsquigarrow", "amssymb-OT1-_rightthreetimes", "amssymb-OT1-_risingdotseq", "amssymb-OT1-_rtimes", "amssymb-OT1-_shortmid", "amssymb-OT1-_shortparallel", "amssymb-OT1-_smallfrown", "amssymb-OT1-_smallsetminus", "amssymb-OT1-_smallsmile", "amssymb-OT1-_sphericalangle", "amssymb-OT1-_sqsubset", "amssymb-OT1-_sqsupset", "amssymb-OT1-_square", "amssymb-OT1-_subseteqq", "amssymb-OT1-_subsetneq", "amssymb-OT1-_subsetneqq", "amssymb-OT1-_succapprox", "amssymb-OT1-_succcurlyeq", "amssymb-OT1-_succnapprox", "amssymb-OT1-_succnsim", "amssymb-OT1-_succsim", "amssymb-OT1-_supseteqq", "amssymb-OT1-_supsetneq", "amssymb-OT1-_supsetneqq", "amssymb-OT1-_therefore", "amssymb-OT1-_thickapprox", "amssymb-OT1-_thicksim", "amssymb-OT1-_triangledown", "amssymb-OT1-_trianglelefteq", "amssymb-OT1-_triangleq", "amssymb-OT1-_trianglerighteq", "amssymb-OT1-_twoheadleftarrow", "amssymb-OT1-_twoheadrightarrow", "amssymb-OT1-_upharpoonleft", "amssymb-OT1-_upharpoonright", "amssymb-OT1-_upuparrows", "amssymb-OT1-_vDash", "amssymb-OT1-_varkappa", "amssymb-OT1-_varnothing", "amssymb-OT1-_varpropto", "amssymb-OT1-_varsubsetneq", "amssymb-OT1-_varsubsetneqq", "amssymb-OT1-_varsupsetneq", "amssymb-OT1-_varsupsetneqq", "amssymb-OT1-_vartriangle", "amssymb-OT1-_vartriangleleft", "amssymb-OT1-_vartriangleright", "amssymb-OT1-_veebar", "bbold-OT1-_mathbb{1}", "cmll-OT1-_parr", "cmll-OT1-_with", "dsfont-OT1-_mathds{1}", "dsfont-OT1-_mathds{A}", "dsfont-OT1-_mathds{B}", "dsfont-OT1-_mathds{C}", "dsfont-OT1-_mathds{D}", "dsfont-OT1-_mathds{E}", "dsfont-OT1-_mathds{F}", "dsfont-OT1-_mathds{G}", "dsfont-OT1-_mathds{H}", "dsfont-OT1-_mathds{I}", "dsfont-OT1-_mathds{J}", "dsfont-OT1-_mathds{K}", "dsfont-OT1-_mathds{L}", "dsfont-OT1-_mathds{M}", "dsfont-OT1-_mathds{N}", "dsfont-OT1-_mathds{O}", "dsfont-OT1-_mathds{P}", "dsfont-OT1-_mathds{Q}", "dsfont-OT1-_mathds{R}", "dsfont-OT1-_mathds{S}", "dsfont-OT1-_mathds{T}", "dsfont-OT1-_mathds{U}", "dsfont-OT1-_mathds{V}", "dsfont-OT1-_mathds{W}", "dsfont-OT1-_mathds{X}", "dsfont-OT1-_mathds{Y}", "dsfont-OT1-_mathds{Z}", "dsfont-OT1-_mathds{h}", "dsfont-OT1-_mathds{k}", "esint-OT1-_dotsint", "esint-OT1-_fint", "esint-OT1-_iiiint", "esint-OT1-_iiint", "esint-OT1-_iint", "esint-OT1-_landdownint", "esint-OT1-_landupint", "esint-OT1-_oiint", "esint-OT1-_ointclockwise", "esint-OT1-_ointctrclockwise", "esint-OT1-_sqiint", "esint-OT1-_sqint", "esint-OT1-_varoiint", "esint-OT1-_varointclockwise", "esint-OT1-_varointctrclockwise", "gensymb-OT1-_celsius", "gensymb-OT1-_degree", "gensymb-OT1-_ohm", "latex2e-OT1-_!`", "latex2e-OT1-_/", "latex2e-OT1-_[", "latex2e-OT1-_]", "latex2e-OT1-_#", "latex2e-OT1-_$", "latex2e-OT1-_%", "latex2e-OT1-_&", "latex2e-OT1-_--", "latex2e-OT1-_---", "latex2e-OT1-_----", "latex2e-OT1-_AA", "latex2e-OT1-_AE", "latex2e-OT1-_Delta", "latex2e-OT1-_Downarrow", "latex2e-OT1-_Gamma", "latex2e-OT1-_Im", "latex2e-OT1-_L", "latex2e-OT1-_Lambda", "latex2e-OT1-_Leftarrow", "latex2e-OT1-_Leftrightarrow", "latex2e-OT1-_Longleftarrow", "latex2e-OT1-_Longleftrightarrow", "latex2e-OT1-_Longrightarrow", "latex2e-OT1-_O", "latex2e-OT1-_OE", "latex2e-OT1-_Omega", "latex2e-OT1-_P", "latex2e-OT1-_Phi", "latex2e-OT1-_Pi", "latex2e-OT1-_Psi", "latex2e-OT1-_Re", "latex2e-OT1-_Rightarrow", "latex2e-OT1-_S", "latex2e-OT1-_SS", "latex2e-OT1-_Sigma", "latex2e-OT1-_Theta", "latex2e-OT1-_Uparrow", "latex2e-OT1-_Updownarrow", "latex2e-OT1-_Upsilon", "latex2e-OT1-_Xi", "latex2e-OT1-__", "latex2e-OT1-_aa", "latex2e-OT1-_ae", "latex2e-OT1-_aleph", "latex2e-OT1-_alpha", "latex2e-OT1-_amalg", "latex2e-OT1-_angle", "latex2e-OT1-_approx", "latex2e-OT1-_ast", "latex2e-OT1-_asymp", "latex2e-OT1-_backslash", "latex2e-OT1-_beta", "latex2e-OT1-_bigcap", "latex2e-OT1-_bigcirc", "latex2e-OT1-_bigcup", "latex2e-OT1-_bigodot", "latex2e-OT1-_bigoplus", "latex2e-OT1-_bigotimes", "latex2e-OT1-_bigsqcup", "latex2e-OT1-_bigtriangledown", "latex2e-OT1-_bigtriangleup", "latex2e-OT1-_biguplus", "latex2e-OT1-_bigvee", "latex2e-OT1-_bigwedge", "latex2e-OT1-_bot", "latex2e-OT1-_bowtie", "latex2e-OT1-_bullet", "latex2e-OT1-_cap", "latex2e-OT1-_cdot", "latex2e-OT1-_cdotp", "latex2e-OT1-_cdots", "latex2e-OT1-_chi", "latex2e-OT1-_circ", "latex2e-OT1-_clubsuit", "latex2e-OT1-_colon", "latex2e-OT1-_cong", "latex2e-OT1-_coprod", "latex2e-OT1-_copyright", "latex2e-OT1-_cup", "latex2e-OT1-_dag", "latex2e-OT1-_dagger", "latex2e-OT1-_dashv", "latex2e-OT1-_ddag", "latex2e-OT1-_ddagger", "latex2e-OT1-_ddots", "latex2e-OT1-_delta", "latex2e-OT1-_diamond", "latex2e-OT1-_diamondsuit", "latex2e-OT1-_div", "latex2e-OT1-_doteq", "latex2e-OT1-_dots", "latex2e-OT1-_downarrow", "latex2e-OT1-_ell", "latex2e-OT1-_emptyset", "latex2e-OT1-_epsilon", "latex2e-OT1-_equiv", "latex2e-OT1-_eta", "latex2e-OT1-_exists", "latex2e-OT1-_flat", "latex2e-OT1-_forall", "latex2e-OT1-_frown", "latex2e-OT1-_gamma", "latex2e-OT1-_hbar", "latex2e-OT1-_heartsuit", "latex2e-OT1-_hookleftarrow", "latex2e-OT1-_hookrightarrow", "latex2e-OT1-_imath", "latex2e-OT1-_in", "latex2e-OT1-_infty", "latex2e-OT1-_int", "latex2e-OT1-_iota", "latex2e-OT1-_jmath", "latex2e-OT1-_kappa", "latex2e-OT1-_l", "latex2e-OT1-_lambda", "latex2e-OT1-_langle", "latex2e-OT1-_lceil", "latex2e-OT1-_ldotp", "latex2e-OT1-_ldots", "latex2e-OT1-_leftarrow", "latex2e-OT1-_leftharpoondown", "latex2e-OT1-_leftharpoonup", "latex2e-OT1-_leftrightarrow", "latex2e-OT1-_lfloor", "latex2e-OT1-_longleftarrow", "latex2e-OT1-_longleftrightarrow", "latex2e-OT1-_longmapsto", "latex2e-OT1-_longrightarrow", "latex2e-OT1-_mapsto", "latex2e-OT1-_mathdollar", "latex2e-OT1-_mathellipsis", "latex2e-OT1-_mathparagraph", "latex2e-OT1-_mathsection", "latex2e-OT1-_mathsterling", "latex2e-OT1-_mathunderscore", "latex2e-OT1-_mid", "latex2e-OT1-_models", "latex2e-OT1-_mp", "latex2e-OT1-_mu", "latex2e-OT1-_nabla", "latex2e-OT1-_natural", "latex2e-OT1-_nearrow", "latex2e-OT1-_neg", "latex2e-OT1-_ni", "latex2e-OT1-_not_approx", "latex2e-OT1-_not_equiv", "latex2e-OT1-_not_sim", "latex2e-OT1-_not_simeq", "latex2e-OT1-_notin", "latex2e-OT1-_nu", "latex2e-OT1-_nwarrow", "latex2e-OT1-_o", "latex2e-OT1-_odot", "latex2e-OT1-_oe", "latex2e-OT1-_oint", "latex2e-OT1-_omega", "latex2e-OT1-_ominus", "latex2e-OT1-_oplus", "latex2e-OT1-_oslash", "latex2e-OT1-_otimes", "latex2e-OT1-_parallel", "latex2e-OT1-_partial", "latex2e-OT1-_perp", "latex2e-OT1-_phi", "latex2e-OT1-_pi", "latex2e-OT1-_pm", "latex2e-OT1-_pounds", "latex2e-OT1-_prec", "latex2e-OT1-_preceq", "latex2e-OT1-_prime", "latex2e-OT1-_prod", "latex2e-OT1-_propto", "latex2e-OT1-_psi", "latex2e-OT1-_rangle", "latex2e-OT1-_rceil", "latex2e-OT1-_rfloor", "latex2e-OT1-_rho", "latex2e-OT1-_rightarrow", "latex2e-OT1-_rightharpoondown", "latex2e-OT1-_rightharpoonup", "latex2e-OT1-_rightleftharpoons", "latex2e-OT1-_searrow", "latex2e-OT1-_setminus", "latex2e-OT1-_sharp", "latex2e-OT1-_sigma", "latex2e-OT1-_sim", "latex2e-OT1-_simeq", "latex2e-OT1-_smile", "latex2e-OT1-_spadesuit", "latex2e-OT1-_sqcap", "latex2e-OT1-_sqcup", "latex2e-OT1-_sqrt{}", "latex2e-OT1-_sqsubseteq", "latex2e-OT1-_sqsupseteq", "latex2e-OT1-_ss", "latex2e-OT1-_star", "latex2e-OT1-_subset", "latex2e-OT1-_subseteq", "latex2e-OT1-_succ", "latex2e-OT1-_succeq", "latex2e-OT1-_sum", "latex2e-OT1-_supset", "latex2e-OT1-_supseteq", "latex2e-OT1-_surd", "latex2e-OT1-_swarrow", "latex2e-OT1-_tau", "latex2e-OT1-_textasciicircum", "latex2e-OT1-_textasciitilde", "latex2e-OT1-_textasteriskcentered", "latex2e-OT1-_textbackslash", "latex2e-OT1-_textbar", "latex2e-OT1-_textbraceleft", "latex2e-OT1-_textbraceright", "latex2e-OT1-_textbullet", "latex2e-OT1-_textcopyright", "latex2e-OT1-_textdagger", "latex2e-OT1-_textdaggerdbl", "latex2e-OT1-_textdollar", "latex2e-OT1-_textellipsis", "latex2e-OT1-_textemdash", "latex2e-OT1-_textendash", "latex2e-OT1-_textexclamdown", "latex2e-OT1-_textgreater", "latex2e-OT1-_textless", "latex2e-OT1-_textordfeminine", "latex2e-OT1-_textordmasculine", "latex2e-OT1-_textparagraph", "latex2e-OT1-_textperiodcentered", "latex2e-OT1-_textquestiondown", "latex2e-OT1-_textquotedblleft", "latex2e-OT1-_textquotedblright", "latex2e-OT1-_textquoteleft", "latex2e-OT1-_textquoteright", "latex2e-OT1-_textregistered", "latex2e-OT1-_textsection", "latex2e-OT1-_textsterling", "latex2e-OT1-_texttrademark", "latex2e-OT1-_textunderscore", "latex2e-OT1-_textvisiblespace", "latex2e-OT1-_theta", "latex2e-OT1-_times", "latex2e-OT1-_top", "latex2e-OT1-_triangle", "latex2e-OT1-_triangleleft", "latex2e-OT1-_triangleright", "latex2e-OT1-_uparrow", "latex2e-OT1-_updownarrow", "latex2e-OT1-_uplus", "latex2e-OT1-_upsilon", "latex2e-OT1-_varepsilon", "latex2e-OT1-_varphi", "latex2e-OT1-_varpi", "latex2e-OT1-_varrho", "latex2e-OT1-_varsigma", "latex2e-OT1-_vartheta", "latex2e-OT1-_vdash", "latex2e-OT1-_vdots", "latex2e-OT1-_vee", "latex2e-OT1-_wedge", "latex2e-OT1-_wp", "latex2e-OT1-_wr", "latex2e-OT1-_xi", "latex2e-OT1-_zeta", "latex2e-OT1-_{", "latex2e-OT1-_|", "latex2e-OT1-_}", "latex2e-OT1-|", "latex2e-T1-_DH", "latex2e-T1-_DJ", "latex2e-T1-_NG", "latex2e-T1-_TH", "latex2e-T1-_dh", "latex2e-T1-_dj", "latex2e-T1-_guillemotleft", "latex2e-T1-_guillemotright", "latex2e-T1-_guilsinglleft", "latex2e-T1-_guilsinglright", "latex2e-T1-_ng", "latex2e-T1-_quotedblbase", "latex2e-T1-_quotesinglbase", "latex2e-T1-_textquotedbl", "latex2e-T1-_th", "latexsym-OT1-_leadsto", "marvosym-OT1-_Ankh", "marvosym-OT1-_Aquarius", "marvosym-OT1-_Aries", "marvosym-OT1-_Bat", "marvosym-OT1-_Cancer", "marvosym-OT1-_Capricorn", "marvosym-OT1-_Celtcross", "marvosym-OT1-_CircledA", "marvosym-OT1-_Cross", "marvosym-OT1-_Denarius", "marvosym-OT1-_EUR", "marvosym-OT1-_EURcr", "marvosym-OT1-_EURdig", "marvosym-OT1-_EURhv", "marvosym-OT1-_EURtm", "marvosym-OT1-_Earth", "marvosym-OT1-_Ecommerce", "marvosym-OT1-_Email", "marvosym-OT1-_Emailct", "marvosym-OT1-_EyesDollar", "marvosym-OT1-_FAX", "marvosym-OT1-_Faxmachine", "marvosym-OT1-_Frowny", "marvosym-OT1-_Gemini", "marvosym-OT1-_Heart", "marvosym-OT1-_Jupiter", "marvosym-OT1-_Leo", "marvosym-OT1-_Letter", "marvosym-OT1-_Libra", "marvosym-OT1-_Lightning", "marvosym-OT1-_MVAt", "marvosym-OT1-_MVRightarrow", "marvosym-OT1-_Mars", "marvosym-OT1-_Mercury", "marvosym-OT1-_Mobilefone", "marvosym-OT1-_Moon", "marvosym-OT1-_Mundus", "marvosym-OT1-_Neptune", "marvosym-OT1-_Pfund", "marvosym-OT1-_Pickup", "marvosym-OT1-_Pisces", "marvosym-OT1-_Pluto", "marvosym-OT1-_Sagittarius", "marvosym-OT1-_Saturn", "marvosym-OT1-_Scorpio", "marvosym-OT1-_Shilling", "marvosym-OT1-_Smiley", "marvosym-OT1-_Sun", "marvosym-OT1-_Taurus", "marvosym-OT1-_Telefon", "marvosym-OT1-_Uranus", "marvosym-OT1-_Venus", "marvosym-OT1-_Virgo", "marvosym-OT1-_Yinyang", "marvosym-OT1-_fax", "mathdots-OT1-_iddots", "mathrsfs-OT1-_mathscr{A}", "mathrsfs-OT1-_mathscr{B}", "mathrsfs-OT1-_mathscr{C}", "mathrsfs-OT1-_mathscr{D}", "mathrsfs-OT1-_mathscr{E}", "mathrsfs-OT1-_mathscr{F}", "mathrsfs-OT1-_mathscr{G}", "mathrsfs-OT1-_mathscr{H}", "mathrsfs-OT1-_mathscr{I}", "mathrsfs-OT1-_mathscr{J}", "mathrsfs-OT1-_mathscr{K}", "mathrsfs-OT1-_mathscr{L}", "mathrsfs-OT1-_mathscr{M}", "mathrsfs-OT1-_mathscr{N}", "mathrsfs-OT1-_mathscr{O}", "mathrsfs-OT1-_mathscr{P}", "mathrsfs-OT1-_mathscr{Q}", "mathrsfs-OT1-_mathscr{R}", "mathrsfs-OT1-_mathscr{S}", "mathrsfs-OT1-_mathscr{T}", "mathrsfs-OT1-_mathscr{U}", "mathrsfs-OT1-_mathscr{V}", "mathrsfs-OT1-_mathscr{W}", "mathrsfs-OT1-_mathscr{X}", "mathrsfs-OT1-_mathscr{Y}", "mathrsfs-OT1-_mathscr{Z}", "skull-OT1-_skull", "stmaryrd-OT1-_Lbag", "stmaryrd-OT1-_Longmapsfrom", "stmaryrd-OT1-_Longmapsto", "stmaryrd-OT1-_Mapsfrom", "stmaryrd-OT1-_Mapsto", "stmaryrd-OT1-_Rbag", "stmaryrd-OT1-_inplus", "stmaryrd-OT1-_lbag", "stmaryrd-OT1-_leftarrowtriangle", "stmaryrd-OT1-_leftrightarroweq", "stmaryrd-OT1-_leftrightarrowtriangle", "stmaryrd-OT1-_lightning", "stmaryrd-OT1-_llbracket", "stmaryrd-OT1-_llceil", "stmaryrd-OT1-_llfloor", "stmaryrd-OT1-_llparenthesis", "stmaryrd-OT1-_longmapsfrom", "stmaryrd-OT1-_mapsfrom", "stmaryrd-OT1-_niplus", "stmaryrd-OT1-_nnearrow", "stmaryrd-OT1-_nnwarrow", "stmaryrd-OT1-_rbag", "stmaryrd-OT1-_rightarrowtriangle", "stmaryrd-OT1-_rrbracket", "stmaryrd-OT1-_rrceil", "stmaryrd-OT1-_rrfloor", "stmaryrd-OT1-_rrparenthesis", "stmaryrd-OT1-_shortdownarrow", "stmaryrd-OT1-_shortleftarrow", "stmaryrd-OT1-_shortrightarrow", "stmaryrd-OT1-_shortuparrow", "stmaryrd-OT1-_ssearrow", "stmaryrd-OT1-_sswarrow", "textcomp-OT1-_textasteriskcentered", "textcomp-OT1-_textbaht", "textcomp-OT1-_textbardbl", "textcomp-OT1-_textbigcircle", "textcomp-OT1-_textblank", "textcomp-OT1-_textbrokenbar", "textcomp-OT1-_textbullet", "textcomp-OT1-_textcent", "textcomp-OT1-_textcentoldstyle", "textcomp-OT1-_textcircledP", "textcomp-OT1-_textcolonmonetary", "textcomp-OT1-_textcopyleft", "textcomp-OT1-_textcopyright", "textcomp-OT1-_textcurrency", "textcomp-OT1-_textdagger", "textcomp-OT1-_textdaggerdbl", "textcomp-OT1-_textdblhyphen", "textcomp-OT1-_textdblhyphenchar", "textcomp-OT1-_textdegree", "textcomp-OT1-_textdiscount", "textcomp-OT1-_textdiv", "textcomp-OT1-_textdollar", "textcomp-OT1-_textdollaroldstyle", "textcomp-OT1-_textdong", "textcomp-OT1-_textdownarrow", "textcomp-OT1-_textestimated", "textcomp-OT1-_texteuro", "textcomp-OT1-_textflorin", "textcomp-OT1-_textfractionsolidus", "textcomp-OT1-_textguarani", "textcomp-OT1-_textinterrobang", "textcomp-OT1-_textinterrobangdown", "textcomp-OT1-_textlangle", "textcomp-OT1-_textlbrackdbl", "textcomp-OT1-_textleftarrow", "textcomp-OT1-_textlira", "textcomp-OT1-_textlnot", "textcomp-OT1-_textlquill", "textcomp-OT1-_textminus", "textcomp-OT1-_textmusicalnote", "textcomp-OT1-_textnaira", "textcomp-OT1-_textnumero", "textcomp-OT1-_textonehalf", "textcomp-OT1-_textonequarter", "textcomp-OT1-_textonesuperior", "textcomp-OT1-_textopenbullet", "textcomp-OT1-_textordfeminine", "textcomp-OT1-_textordmasculine", "textcomp-OT1-_textparagraph", "textcomp-OT1-_textperiodcentered", "textcomp-OT1-_textpertenthousand", "textcomp-OT1-_textperthousand", "textcomp-OT1-_textpeso", "textcomp-OT1-_textpilcrow", "textcomp-OT1-_textpm", "textcomp-OT1-_textquotesingle", "textcomp-OT1-_textquotestraightbase", "textcomp-OT1-_textquotestraightdblbase", "textcomp-OT1-_textrangle", "textcomp-OT1-_textrbrackdbl", "textcomp-OT1-_textrecipe", "textcomp-OT1-_textreferencemark", "textcomp-OT1-_textregistered", "textcomp-OT1-_textrightarrow", "textcomp-OT1-_textrquill", "textcomp-OT1-_textsection", "textcomp-OT1-_textservicemark", "textcomp-OT1-_textsterling", "textcomp-OT1-_textsurd", "textcomp-OT1-_textthreequarters", "textcomp-OT1-_textthreequartersemdash", "textcomp-OT1-_textthreesuperior", "textcomp-OT1-_texttildelow", "textcomp-OT1-_texttimes", "textcomp-OT1-_texttrademark", "textcomp-OT1-_texttwelveudash", "textcomp-OT1-_texttwosuperior", "textcomp-OT1-_textuparrow", "textcomp-OT1-_textwon", "textcomp-OT1-_textyen", "tipa-OT1-_textObardotlessj", "tipa-OT1-_textOlyoghlig", "tipa-OT1-_textbabygamma", "tipa-OT1-_textbarb", "tipa-OT1-_textbarc", "tipa-OT1-_textbard", "tipa-OT1-_textbardotlessj", "tipa-OT1-_textbarg", "tipa-OT1-_textbarglotstop", "tipa-OT1-_textbari", "tipa-OT1-_textbarl", "tipa-OT1-_textbaro", "tipa-OT1-_textbarrevglotstop", "tipa-OT1-_textbaru", "tipa-OT1-_textbeltl", "tipa-OT1-_textbeta", "tipa-OT1-_textbullseye", "tipa-OT1-_textceltpal", "tipa-OT1-_textchi", "tipa-OT1-_textcloseepsilon", "tipa-OT1-_textcloseomega", "tipa-OT1-_textcloserevepsilon", "tipa-OT1-_textcommatailz", "tipa-OT1-_textcorner", "tipa-OT1-_textcrb", "tipa-OT1-_textcrd", "tipa-OT1-_textcrg", "tipa-OT1-_textcrh", "tipa-OT1-_textcrinvglotstop", "tipa-OT1-_textcrlambda", "tipa-OT1-_textcrtwo", "tipa-OT1-_textctc", "tipa-OT1-_textctd", "tipa-OT1-_textctdctzlig", "tipa-OT1-_textctesh", "tipa-OT1-_textctj", "tipa-OT1-_textctn", "tipa-OT1-_textctt", "tipa-OT1-_textcttctclig", "tipa-OT1-_textctyogh", "tipa-OT1-_textctz", "tipa-OT1-_textdctzlig", "tipa-OT1-_textdoublebaresh", "tipa-OT1-_textdoublebarpipe", "tipa-OT1-_textdoublebarslash", "tipa-OT1-_textdoublepipe", "tipa-OT1-_textdoublevertline", "tipa-OT1-_textdownstep", "tipa-OT1-_textdyoghlig", "tipa-OT1-_textdzlig", "tipa-OT1-_textepsilon", "tipa-OT1-_textesh", "tipa-OT1-_textfishhookr", "tipa-OT1-_textg", "tipa-OT1-_textgamma", "tipa-OT1-_textglobfall", "tipa-OT1-_textglobrise", "tipa-OT1-_textglotstop", "tipa-OT1-_texthalflength", "tipa-OT1-_texthardsign", "tipa-OT1-_texthooktop", "tipa-OT1-_texthtb", "tipa-OT1-_texthtbardotlessj", "tipa-OT1-_texthtc", "tipa-OT1-_texthtd", "tipa-OT1-_texthtg", "tipa-OT1-_texthth", "tipa-OT1-_texththeng", "tipa-OT1-_texthtk", "tipa-OT1-_texthtp", "tipa-OT1-_texthtq", "tipa-OT1-_texthtrtaild", "tipa-OT1-_texthtscg", "tipa-OT1-_texthtt", "tipa-OT1-_texthvlig", "tipa-OT1-_textinvglotstop", "tipa-OT1-_textinvscr", "tipa-OT1-_textiota", "tipa-OT1-_textlambda", "tipa-OT1-_textlengthmark", "tipa-OT1-_textlhookt", "tipa-OT1-_textlhtlongi", "tipa-OT1-_textlhtlongy", "tipa-OT1-_textlonglegr", "tipa-OT1-_textlptr", "tipa-OT1-_textltailm", "tipa-OT1-_textltailn", "tipa-OT1-_textltilde", "tipa-OT1-_textlyoghlig", "tipa-OT1-_textomega", "tipa-OT1-_textopencorner", "tipa-OT1-_textopeno", "tipa-OT1-_textpalhook", "tipa-OT1-_textphi", "tipa-OT1-_textpipe", "tipa-OT1-_textprimstress", "tipa-OT1-_textraiseglotstop", "tipa-OT1-_textraisevibyi", "tipa-OT1-_textramshorns", "tipa-OT1-_textrevapostrophe", "tipa-OT1-_textreve", "tipa-OT1-_textrevepsilon", "tipa-OT1-_textrevglotstop", "tipa-OT1-_textrevyogh", "tipa-OT1-_textrhookrevepsilon", "tipa-OT1-_textrhookschwa", "tipa-OT1-_textrhoticity", "tipa-OT1-_textrptr", "tipa-OT1-_textrtaild", "tipa-OT1-_textrtaill", "tipa-OT1-_textrtailn", "tipa-OT1-_textrtailr", "tipa-OT1-_textrtails", "tipa-OT1-_textrtailt", "tipa-OT1-_textrtailz", "tipa-OT1-_textrthook", "tipa-OT1-_textsca", "tipa-OT1-_textscb", "tipa-OT1-_textsce", "tipa-OT1-_textscg", "tipa-OT1-_textsch", "tipa-OT1-_textschwa", "tipa-OT1-_textsci", "tipa-OT1-_textscj", "tipa-OT1-_textscl", "tipa-OT1-_textscn", "tipa-OT1-_textscoelig", "tipa-OT1-_textscomega", "tipa-OT1-_textscr", "tipa-OT1-_textscripta", "tipa-OT1-_textscriptg", "tipa-OT1-_textscriptv", "tipa-OT1-_textscu", "tipa-OT1-_textscy", "tipa-OT1-_textsecstress", "tipa-OT1-_textsoftsign", "tipa-OT1-_textstretchc", "tipa-OT1-_texttctclig", "tipa-OT1-_textteshlig", "tipa-OT1-_texttheta", "tipa-OT1-_textthorn", "tipa-OT1-_texttoneletterstem", "tipa-OT1-_texttslig", "tipa-OT1-_textturna", "tipa-OT1-_textturncelig", "tipa-OT1-_textturnh", "tipa-OT1-_textturnk", "tipa-OT1-_textturnlonglegr", "tipa-OT1-_textturnm", "tipa-OT1-_textturnmrleg", "tipa-OT1-_textturnr", "tipa-OT1-_textturnrrtail", "tipa-OT1-_textturnscripta", "tipa-OT1-_textturnt", "tipa-OT1-_textturnv", "tipa-OT1-_textturnw", "tipa-OT1-_textturny", "tipa-OT1-_textupsilon", "tipa-OT1-_textupstep", "tipa-OT1-_textvertline", "tipa-OT1-_textvibyi", "tipa-OT1-_textvibyy", "tipa-OT1-_textwynn", "tipa-OT1-_textyogh", "upgreek-OT1-_Updelta", "upgreek-OT1-_Upgamma", "upgreek-OT1-_Uplambda", "upgreek-OT1-_Upomega", "upgreek-OT1-_Upphi", "upgreek-OT1-_Uppi", "upgreek-OT1-_Uppsi", "upgreek-OT1-_Upsigma", "upgreek-OT1-_Uptheta", "upgreek-OT1-_Upupsilon", "upgreek-OT1-_Upxi", "upgreek-OT1-_upalpha", "upgreek-OT1-_upbeta", "upgreek-OT1-_upchi", "upgreek-OT1-_updelta", "upgreek-OT1-_upepsilon", "upgreek-OT1-_upeta", "upgreek-OT1-_upgamma", "upgreek-OT1-_upiota", "upgreek-OT1-_upkappa", "upgreek-OT1-_uplambda", "upgreek-OT1-_upmu", "upgreek-OT1-_upnu", "upgreek-OT1-_upomega", "upgreek-OT1-_upphi", "upgreek-OT1-_uppi", "upgreek-OT1-_uppsi", "upgreek-OT1-_uprho", "upgreek-OT1-_upsigma", "upgreek-OT1-_uptau", "upgreek-OT1-_uptheta", "upgreek-OT1-_upupsilon", "upgreek-OT1-_upvarepsilon", "upgreek-OT1-_upvarphi", "upgreek-OT1-_upvarpi", "upgreek-OT1-_upvarrho", "upgreek-OT1-_upvarsigma", "upgreek-OT1-_upvartheta", "upgreek-OT1-_upxi", "upgreek-OT1-_upzeta", "wasysym-OT1-_Bowtie", "wasysym-OT1-_DOWNarrow", "wasysym-OT1-_LEFTarrow", "wasysym-OT1-_LHD", "wasysym-OT1-_RHD", "wasysym-OT1-_RIGHTarrow", "wasysym-OT1-_UParrow", "wasysym-OT1-_aquarius", "wasysym-OT1-_aries", "wasysym-OT1-_ascnode", "wasysym-OT1-_astrosun", "wasysym-OT1-_ataribox", "wasysym-OT1-_bell", "wasysym-OT1-_blacksmiley", "wasysym-OT1-_brokenvert", "wasysym-OT1-_cancer", "wasysym-OT1-_capricornus", "wasysym-OT1-_cent", "wasysym-OT1-_checked", "wasysym-OT1-_clock", "wasysym-OT1-_conjunction", "wasysym-OT1-_currency", "wasysym-OT1-_descnode", "wasysym-OT1-_diameter", "wasysym-OT1-_earth", "wasysym-OT1-_female", "wasysym-OT1-_frownie", "wasysym-OT1-_fullmoon", "wasysym-OT1-_gemini", "wasysym-OT1-_invdiameter", "wasysym-OT1-_jupiter", "wasysym-OT1-_kreuz", "wasysym-OT1-_leftmoon", "wasysym-OT1-_leo", "wasysym-OT1-_lhd", "wasysym-OT1-_libra", "wasysym-OT1-_lightning", "wasysym-OT1-_male", "wasysym-OT1-_mars", "wasysym-OT1-_mercury", "wasysym-OT1-_neptune", "wasysym-OT1-_newmoon", "wasysym-OT1-_ocircle", "wasysym-OT1-_opposition", "wasysym-OT1-_permil", "wasysym-OT1-_phone", "wasysym-OT1-_pisces", "wasysym-OT1-_pluto", "wasysym-OT1-_pointer", "wasysym-OT1-_recorder", "wasysym-OT1-_rhd", "wasysym-OT1-_rightmoon", "wasysym-OT1-_sagittarius", "wasysym-OT1-_saturn", "wasysym-OT1-_scorpio", "wasysym-OT1-_smiley", "wasysym-OT1-_sun", "wasysym-OT1-_taurus", "wasysym-OT1-_unlhd", "wasysym-OT1-_unrhd", "wasysym-OT1-_uranus", "wasysym-OT1-_venus", "wasysym-OT1-_vernal", "wasysym-OT1-_virgo", "wasysym-OT1-_wasylozenge"];
-
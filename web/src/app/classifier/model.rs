@@ -1,80 +1,159 @@
-use std::hash::{Hash, Hasher};
-use std::iter::zip;
-use burn::backend::ndarray::NdArrayDevice;
-use burn::tensor::activation::softmax;
+use burn::prelude::Backend;
+use shared::calibration::CalibrationConfig;
+use shared::classifier::{name_ranked, top_k, RankedSymbol};
+use shared::custom_symbols::CustomSymbolLibrary;
 use shared::item::{HEIGHT, WIDTH};
 use shared::model::Model;
-use crate::app::classifier::keys;
-use crate::app::classifier::state::{build_and_load_model, MyB};
+use crate::app::classifier::state::{build_and_load_model, labels, load_calibration};
+use serde::{Deserialize, Serialize};
 
-pub struct SharedModel {
-    model: Option<Model<MyB>>,
-    device: NdArrayDevice,
-}
+// Drawings per forward pass in `inference_batch`
+#[cfg(feature = "ssr")]
+pub const BATCH_SIZE: usize = 64;
 
-#[derive(Clone)]
-pub struct Prediction {
-    pub symbol: String,
-    pub probability: f32
+pub struct SharedModel<B: Backend> {
+    model: Option<Model<B>>,
+    calibration: CalibrationConfig,
+    device: B::Device,
 }
 
-impl PartialEq for Prediction {
-    fn eq(&self, other: &Self) -> bool {
-        self.symbol == other.symbol
-    }
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Classification {
+    Recognised(Vec<RankedSymbol>),
+    // The calibrated top-1 probability fell below the rejection threshold
+    NotRecognised
 }
 
-impl Eq for Prediction {}
-
-impl Hash for Prediction {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.symbol.hash(state);
-    }
-}
-
-impl SharedModel {
-    pub fn new() -> Self {
+impl<B: Backend> SharedModel<B> {
+    pub fn new(device: B::Device) -> Self {
         Self {
             model: None,
-            device: NdArrayDevice::default(),
+            calibration: load_calibration(),
+            device,
         }
     }
 
-    pub async fn inference(&mut self, image: [[f32; WIDTH]; HEIGHT]) -> Vec<Prediction> {
-        use burn::prelude::*;
-
+    async fn loaded_model(&mut self) -> &Model<B> {
         // Lazy-load the model
         if self.model.is_none() {
-            self.model = Some(build_and_load_model().await);
+            self.model = Some(build_and_load_model(&self.device).await);
         }
 
-        let model = self.model.as_ref().unwrap();
+        self.model.as_ref().unwrap()
+    }
+
+    /// Normalised embedding of a drawing, used to register custom symbol examples.
+    #[cfg(feature = "hydrate")]
+    pub async fn embed(&mut self, image: [[f32; WIDTH]; HEIGHT]) -> Vec<f32> {
+        use burn::prelude::*;
 
-        // Create tensor and reshape to [batch, height, width]
-        let tensor = Tensor::<MyB, 2>::from_floats(image, &self.device)
+        let device = self.device.clone();
+        let tensor = Tensor::<B, 2>::from_floats(image, &device)
             .unsqueeze();
+        let embedding = self.loaded_model().await.embed(tensor);
+
+        embedding
+            .to_data_async()
+            .await
+            .to_vec::<f32>()
+            .unwrap()
+    }
+
+    /// The `k` most likely symbols, or `NotRecognised` if even the best falls below the threshold.
+    pub async fn inference(&mut self, image: [[f32; WIDTH]; HEIGHT], custom_symbols: &CustomSymbolLibrary, k: usize) -> Classification {
+        let predictions = self.ranked(image, custom_symbols, k).await;
+        self.recognise(predictions)
+    }
 
-        // Run forward pass
-        let output: Tensor<MyB, 1> = model.forward(tensor).squeeze();
-        let probabilities = softmax(output.clone(), 0);
+    /// The `k` most likely symbols, best first, without applying the rejection threshold.
+    pub async fn ranked(&mut self, image: [[f32; WIDTH]; HEIGHT], custom_symbols: &CustomSymbolLibrary, k: usize) -> Vec<RankedSymbol> {
+        use burn::prelude::*;
+
+        let device = self.device.clone();
+        let prototypes = custom_symbols.prototypes::<B>(&device);
+        let custom_keys: Vec<String> = custom_symbols.ready()
+            .iter()
+            .map(|symbol| symbol.key())
+            .collect();
+        let model = self.loaded_model().await;
+
+        // Create tensor and reshape to [batch, height, width]
+        let tensor = Tensor::<B, 2>::from_floats(image, &device)
+            .unsqueeze();
 
-        let topk = probabilities
-            .topk_with_indices(5, 0);
+        // Run forward pass, scoring custom symbols alongside the trained classes
+        let output = match prototypes {
+            Some(prototypes) => model.forward_with_prototypes(tensor, prototypes),
+            None => model.forward(tensor)
+        };
+        let (values, indices) = top_k(&self.calibration, output, k);
 
-        let predicted_idx = topk.1
+        let indices = indices
             .to_data_async()
             .await
-            .to_vec::<i32>()
+            .convert::<i64>()
+            .to_vec::<i64>()
             .unwrap();
-        let predicted_values = (topk.0 * 100)
+        let values = values
             .to_data_async()
             .await
-            .to_vec::<f32>().unwrap();
+            .to_vec::<f32>()
+            .unwrap();
+
+        name_ranked(&indices, &values, &labels().keys, &custom_keys)
+    }
+
+    /// Classifies many drawings with one forward pass per `BATCH_SIZE` images, in input order.
+    /// Custom symbols aren't scored; batches come from the server and CLI, which have none.
+    #[cfg(feature = "ssr")]
+    pub async fn inference_batch(&mut self, images: &[[[f32; WIDTH]; HEIGHT]], k: usize) -> Vec<Classification> {
+        let device = self.device.clone();
+        let mut classifications = Vec::with_capacity(images.len());
+        for chunk in images.chunks(BATCH_SIZE) {
+            let tensor = batch_images::<B>(chunk, &device);
+            let output = self.loaded_model().await.forward(tensor);
+            let (values, indices) = top_k(&self.calibration, output, k);
+            let k = indices.dims()[1];
 
-        let predictions: Vec<Prediction> = zip(predicted_idx, predicted_values)
-            .map(|(idx, value)| Prediction { symbol: keys::KEYS[idx as usize].to_string(), probability: value })
-            .collect::<Vec<_>>();
+            let indices = indices
+                .to_data_async()
+                .await
+                .convert::<i64>()
+                .to_vec::<i64>()
+                .unwrap();
+            let values = values
+                .to_data_async()
+                .await
+                .to_vec::<f32>()
+                .unwrap();
 
-        predictions
+            // Rows of the [batch, k] results, one per image
+            for (indices, values) in indices.chunks(k).zip(values.chunks(k)) {
+                classifications.push(self.recognise(name_ranked(indices, values, &labels().keys, &[])));
+            }
+        }
+
+        classifications
+    }
+
+    /// Applies the rejection threshold to predictions ranked best first.
+    fn recognise(&self, predictions: Vec<RankedSymbol>) -> Classification {
+        match predictions.first() {
+            Some(best) if self.calibration.is_recognised(best.probability as f64) => Classification::Recognised(predictions),
+            _ => Classification::NotRecognised
+        }
     }
+}
+
+/// Stacks drawings into a [batch, height, width] tensor, the way `DetexifyBatcher` does for training.
+#[cfg(feature = "ssr")]
+fn batch_images<B: Backend>(images: &[[[f32; WIDTH]; HEIGHT]], device: &B::Device) -> burn::Tensor<B, 3> {
+    use burn::prelude::*;
+
+    let images = images.iter()
+        .map(|image| Tensor::<B, 2>::from_floats(*image, device))
+        .map(|tensor| tensor.reshape([1, HEIGHT, WIDTH]))
+        .collect();
+
+    Tensor::cat(images, 0)
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::app::classifier::model::Classification;
+use serde::{Deserialize, Serialize};
+use shared::custom_symbols::CustomSymbolLibrary;
+use shared::image_processing::rasterize_strokes;
+use shared::item::{HEIGHT, WIDTH};
+use std::fmt;
+
+/// A drawing to classify, either already rasterized or as detexify strokes.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum InferenceInput {
+    // Boxed so every input isn't the size of a raster
+    Image(Box<[[f32; WIDTH]; HEIGHT]>),
+    Strokes(Vec<Vec<[f32; 3]>>)
+}
+
+impl InferenceInput {
+    pub fn to_image(&self) -> [[f32; WIDTH]; HEIGHT] {
+        match self {
+            InferenceInput::Image(image) => **image,
+            InferenceInput::Strokes(strokes) => rasterize_strokes(strokes)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum InferenceBackend {
+    WebGpu,
+    Cpu
+}
+
+impl fmt::Display for InferenceBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InferenceBackend::WebGpu => write!(f, "WebGPU"),
+            InferenceBackend::Cpu => write!(f, "CPU (NdArray)")
+        }
+    }
+}
+
+/// Messages from the page to the inference worker, posted as JSON strings.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum WorkerRequest {
+    // Superseded by any later `Classify` that arrives before the worker gets to it
+    Classify { id: u64, input: InferenceInput, k: usize },
+    // Always answered, in order
+    Embed { id: u64, input: InferenceInput },
+    SetCustomSymbols(CustomSymbolLibrary)
+}
+
+/// Messages from the inference worker back to the page, echoing the request id.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum WorkerResponse {
+    // Sent once the worker has picked a backend
+    Ready { backend: InferenceBackend },
+    // `image` is the model input, for the debug preview
+    Classified { id: u64, image: Box<[[f32; WIDTH]; HEIGHT]>, classification: Classification, latency_ms: f64 },
+    Embedded { id: u64, embedding: Vec<f32> }
+}
@@ -1,22 +1,70 @@
 use burn::backend::NdArray;
+use burn::prelude::Backend;
 use burn::{
+    config::Config,
     module::Module,
     record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
 };
-use shared::model::{Model, ModelConfig};
+use shared::calibration::CalibrationConfig;
+use shared::custom_symbols::model_fingerprint;
+use shared::labels::Labels;
+use shared::model::{Model, ModelConfig, HIDDEN_SIZE};
+use std::sync::OnceLock;
 
+// Training writes these to its artifact directory; copy them to the repository root (see the README)
 static STATE_ENCODED: &[u8] = include_bytes!("../../../../model.bin");
+static CALIBRATION_ENCODED: &[u8] = include_bytes!("../../../../calibration.json");
+static LABELS_ENCODED: &[u8] = include_bytes!("../../../../labels.json");
 
-pub type MyB = NdArray<f32, i32>;
+pub type CpuBackend = NdArray<f32, i32>;
+// Only the browser's worker runs on WebGPU
+#[cfg(feature = "hydrate")]
+pub type GpuBackend = burn::backend::Wgpu<f32, i32>;
 
 
 /// Builds and loads trained parameters into the model.
-pub async fn build_and_load_model() -> Model<MyB> {
-    let model: Model<MyB> = ModelConfig::new(1098, 256)
-        .init(&Default::default());
+pub async fn build_and_load_model<B: Backend>(device: &B::Device) -> Model<B> {
+    let model: Model<B> = ModelConfig::new(labels().len(), HIDDEN_SIZE)
+        .init(device);
     let record = BinBytesRecorder::<FullPrecisionSettings, &'static [u8]>::default()
-        .load(STATE_ENCODED, &Default::default())
+        .load(STATE_ENCODED, device)
         .expect("Failed to decode state");
 
     model.load_record(record)
-}
\ No newline at end of file
+}
+
+/// Loads the temperature and rejection threshold fitted alongside the model.
+pub fn load_calibration() -> CalibrationConfig {
+    CalibrationConfig::load_binary(CALIBRATION_ENCODED)
+        .expect("Failed to decode calibration")
+}
+
+/// The key of each model output, in order; an expanded model has more than the original 1098.
+pub fn labels() -> &'static Labels {
+    static LABELS: OnceLock<Labels> = OnceLock::new();
+    LABELS.get_or_init(|| Labels::load_binary(LABELS_ENCODED).expect("Failed to decode labels"))
+}
+
+/// Fingerprint of the embedded model, stamped on custom symbol libraries it embeds.
+pub fn model_id() -> &'static str {
+    static MODEL_ID: OnceLock<String> = OnceLock::new();
+    MODEL_ID.get_or_init(|| model_fingerprint(STATE_ENCODED))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::labels;
+    use shared::symbols::SymbolCatalogue;
+
+    #[test]
+    #[ignore = "needs the trained labels.json at the repository root"]
+    fn every_key_has_a_catalogue_entry() {
+        let catalogue = SymbolCatalogue::embedded();
+        assert!(!labels().keys.is_empty(), "labels.json has no keys; copy it from the training artifacts");
+        let missing: Vec<&String> = labels().keys.iter()
+            .filter(|key| catalogue.get(key).is_none())
+            .collect();
+
+        assert!(missing.is_empty(), "Keys missing from symbols.json: {missing:?}");
+    }
+}
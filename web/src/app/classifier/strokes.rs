@@ -0,0 +1,174 @@
+use leptos::wasm_bindgen::{Clamped, JsCast};
+use shared::item::{HEIGHT, WIDTH};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+
+const LINE_WIDTH: f64 = 4.0;
+
+// Pressure recorded for pointers that don't report any, per the Pointer Events spec
+const DEFAULT_PRESSURE: f32 = 0.5;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stroke {
+    /// Detexify `[x, y, t]` points: CSS pixels and milliseconds.
+    pub points: Vec<[f32; 3]>,
+    /// Pen pressure in `0.0..=1.0`, one per point.
+    pub pressures: Vec<f32>
+}
+
+impl Stroke {
+    pub fn new(x: f64, y: f64, pressure: f32) -> Self {
+        let mut stroke = Self::default();
+        stroke.push(x, y, pressure);
+        stroke
+    }
+
+    pub fn push(&mut self, x: f64, y: f64, pressure: f32) {
+        self.points.push(point(x, y));
+        self.pressures.push(if pressure > 0.0 { pressure } else { DEFAULT_PRESSURE });
+    }
+}
+
+/// The strokes in the detexify `[[[x, y, t], ...], ...]` format used for training.
+pub fn to_detexify(strokes: &[Stroke]) -> Vec<Vec<[f32; 3]>> {
+    strokes.iter().map(|stroke| stroke.points.clone()).collect()
+}
+
+pub fn context_2d(canvas: &HtmlCanvasElement) -> CanvasRenderingContext2d {
+    canvas
+        .get_context("2d")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<CanvasRenderingContext2d>()
+        .unwrap()
+}
+
+/// Milliseconds since page load, small enough to keep millisecond precision as `f32`.
+pub fn timestamp() -> f32 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now() as f32)
+        .unwrap_or_default()
+}
+
+fn point(x: f64, y: f64) -> [f32; 3] {
+    [x as f32, y as f32, timestamp()]
+}
+
+/// Sizes the canvas backing store to its on-screen size at the device pixel ratio.
+pub fn fit_to_container(canvas: &HtmlCanvasElement) {
+    let ratio = web_sys::window()
+        .map(|window| window.device_pixel_ratio())
+        .unwrap_or(1.0);
+    let rect = canvas.get_bounding_client_rect();
+
+    canvas.set_width((rect.width() * ratio).round().max(1.0) as u32);
+    canvas.set_height((rect.height() * ratio).round().max(1.0) as u32);
+}
+
+/// Clears the canvas and redraws every stroke as quadratic curves through the point midpoints.
+pub fn render_strokes(canvas: &HtmlCanvasElement, strokes: &[Stroke]) {
+    let ctx = context_2d(canvas);
+    let rect = canvas.get_bounding_client_rect();
+    let scale = if rect.width() > 0.0 { canvas.width() as f64 / rect.width() } else { 1.0 };
+
+    // Draw in CSS pixels, matching the coordinates the strokes are recorded in
+    ctx.set_transform(scale, 0.0, 0.0, scale, 0.0, 0.0).unwrap();
+    ctx.clear_rect(0.0, 0.0, rect.width(), rect.height());
+
+    ctx.set_line_width(LINE_WIDTH);
+    ctx.set_line_cap("round");
+    ctx.set_line_join("round");
+    ctx.set_stroke_style_str("black");
+
+    for stroke in strokes.iter().map(|stroke| &stroke.points) {
+        let Some(&[x0, y0, _]) = stroke.first() else { continue };
+        ctx.begin_path();
+        ctx.move_to(x0 as f64, y0 as f64);
+        // A single point still needs a segment for the round cap to draw a dot
+        if stroke.len() == 1 {
+            ctx.line_to(x0 as f64, y0 as f64);
+        }
+        for window in stroke[1..].windows(2) {
+            let [x1, y1, _] = window[0];
+            let [x2, y2, _] = window[1];
+            let (mid_x, mid_y) = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+            ctx.quadratic_curve_to(x1 as f64, y1 as f64, mid_x as f64, mid_y as f64);
+        }
+        if let Some(&[x, y, _]) = stroke.last() {
+            ctx.line_to(x as f64, y as f64);
+        }
+        ctx.stroke();
+    }
+}
+
+/// Paints the `WIDTH`x`HEIGHT` model input, ink dark on white, one canvas pixel per value.
+pub fn render_model_input(canvas: &HtmlCanvasElement, image: &[[f32; WIDTH]; HEIGHT]) {
+    let mut pixels = Vec::with_capacity(WIDTH * HEIGHT * 4);
+    for row in image {
+        for &value in row {
+            let shade = 255 - (value.clamp(0.0, 1.0) * 255.0) as u8;
+            pixels.extend_from_slice(&[shade, shade, shade, 255]);
+        }
+    }
+
+    let image_data = ImageData::new_with_u8_clamped_array_and_sh(Clamped(&pixels), WIDTH as u32, HEIGHT as u32)
+        .unwrap();
+    context_2d(canvas).put_image_data(&image_data, 0.0, 0.0).unwrap();
+}
+
+fn distance_to_segment(x: f32, y: f32, [x1, y1, _]: [f32; 3], [x2, y2, _]: [f32; 3]) -> f32 {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (((x - x1) * dx + (y - y1) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (px, py) = (x1 + t * dx, y1 + t * dy);
+
+    ((x - px).powi(2) + (y - py).powi(2)).sqrt()
+}
+
+/// Index of the topmost stroke passing within `tolerance` CSS pixels of `(x, y)`.
+pub fn stroke_at(strokes: &[Stroke], x: f32, y: f32, tolerance: f32) -> Option<usize> {
+    strokes.iter().rposition(|stroke| match stroke.points.as_slice() {
+        [] => false,
+        [only] => distance_to_segment(x, y, *only, *only) <= tolerance,
+        points => points.windows(2).any(|segment| distance_to_segment(x, y, segment[0], segment[1]) <= tolerance)
+    })
+}
+
+/// Undo/redo stacks of whole-drawing snapshots, so every kind of edit is reversible.
+#[derive(Clone, Default)]
+pub struct History {
+    past: Vec<Vec<Stroke>>,
+    future: Vec<Vec<Stroke>>
+}
+
+impl History {
+    /// Remembers the drawing as it was before an edit; a new edit discards the redo stack.
+    pub fn record(&mut self, before: Vec<Stroke>) {
+        self.past.push(before);
+        self.future.clear();
+    }
+
+    pub fn undo(&mut self, current: Vec<Stroke>) -> Option<Vec<Stroke>> {
+        let previous = self.past.pop()?;
+        self.future.push(current);
+        Some(previous)
+    }
+
+    pub fn redo(&mut self, current: Vec<Stroke>) -> Option<Vec<Stroke>> {
+        let next = self.future.pop()?;
+        self.past.push(current);
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
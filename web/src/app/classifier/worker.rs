@@ -0,0 +1,139 @@
+use crate::app::classifier::engine::Engine;
+use crate::app::classifier::protocol::{InferenceInput, WorkerRequest, WorkerResponse};
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use shared::custom_symbols::CustomSymbolLibrary;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+struct Pending {
+    embeds: VecDeque<(u64, InferenceInput)>,
+    // Only the newest classification is kept; older ones are stale by the time we get to them
+    classify: Option<(u64, InferenceInput, usize)>,
+    custom_symbols: CustomSymbolLibrary,
+    running: bool
+}
+
+enum Job {
+    Embed(u64, InferenceInput),
+    Classify(u64, InferenceInput, usize, CustomSymbolLibrary)
+}
+
+impl Pending {
+    fn new() -> Self {
+        Self {
+            embeds: VecDeque::new(),
+            classify: None,
+            custom_symbols: CustomSymbolLibrary::new(),
+            running: false
+        }
+    }
+
+    fn next_job(&mut self) -> Option<Job> {
+        self.embeds.pop_front()
+            .map(|(id, input)| Job::Embed(id, input))
+            .or_else(|| {
+                let (id, input, k) = self.classify.take()?;
+                Some(Job::Classify(id, input, k, self.custom_symbols.clone()))
+            })
+    }
+}
+
+/// Resolves on the next macrotask, after any messages already queued have been delivered.
+async fn yield_to_event_loop(scope: &DedicatedWorkerGlobalScope) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let _ = scope.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0);
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+fn post(scope: &DedicatedWorkerGlobalScope, response: &WorkerResponse) {
+    let json = serde_json::to_string(response).expect("Worker responses should serialize");
+    let _ = scope.post_message(&JsValue::from_str(&json));
+}
+
+fn now(scope: &DedicatedWorkerGlobalScope) -> f64 {
+    scope.performance().map(|performance| performance.now()).unwrap_or_default()
+}
+
+async fn drain(scope: DedicatedWorkerGlobalScope, slot: Rc<RefCell<Option<Engine>>>, pending: Rc<RefCell<Pending>>) {
+    // Only one drain runs at a time; it holds the engine itself so no borrow lives across an await
+    let taken = slot.borrow_mut().take();
+    let mut engine = match taken {
+        Some(engine) => engine,
+        None => {
+            let detected = Engine::detect().await;
+            post(&scope, &WorkerResponse::Ready { backend: detected.backend() });
+            detected
+        }
+    };
+
+    loop {
+        // Let a burst of requests land first so only the newest one is evaluated
+        yield_to_event_loop(&scope).await;
+
+        let job = {
+            let mut pending = pending.borrow_mut();
+            let job = pending.next_job();
+            if job.is_none() {
+                pending.running = false;
+            }
+            job
+        };
+
+        let response = match job {
+            None => break,
+            Some(Job::Embed(id, input)) => {
+                let embedding = engine.embed(input.to_image()).await;
+                WorkerResponse::Embedded { id, embedding }
+            }
+            Some(Job::Classify(id, input, k, custom_symbols)) => {
+                let image = input.to_image();
+                let start = now(&scope);
+                let classification = engine.inference(image, &custom_symbols, k).await;
+                let latency_ms = now(&scope) - start;
+                WorkerResponse::Classified { id, image: Box::new(image), classification, latency_ms }
+            }
+        };
+        post(&scope, &response);
+    }
+
+    *slot.borrow_mut() = Some(engine);
+}
+
+/// Entry point of `inference-worker.js`: answers `WorkerRequest`s posted by the page.
+pub fn start() {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    // Chosen by the first drain, since detecting WebGPU is asynchronous
+    let engine = Rc::new(RefCell::new(None));
+    let pending = Rc::new(RefCell::new(Pending::new()));
+
+    let worker_scope = scope.clone();
+    let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let Some(json) = event.data().as_string() else { return };
+        let Ok(request) = serde_json::from_str::<WorkerRequest>(&json) else { return };
+
+        let mut queue = pending.borrow_mut();
+        match request {
+            WorkerRequest::Classify { id, input, k } => queue.classify = Some((id, input, k)),
+            WorkerRequest::Embed { id, input } => queue.embeds.push_back((id, input)),
+            WorkerRequest::SetCustomSymbols(library) => {
+                queue.custom_symbols = library;
+                return;
+            }
+        }
+        if queue.running {
+            return;
+        }
+        queue.running = true;
+        drop(queue);
+        wasm_bindgen_futures::spawn_local(drain(worker_scope.clone(), Rc::clone(&engine), Rc::clone(&pending)));
+    });
+
+    scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    // The worker lives as long as the page, so the handler is never dropped
+    on_message.forget();
+}
@@ -0,0 +1,41 @@
+use leptos::html::InnerHtmlAttribute;
+use leptos::prelude::{AriaAttributes, ElementChild, GlobalAttributes, IntoAny, StyleAttribute};
+use leptos::{component, view, IntoView};
+use shared::rendering::{render_url, RenderingManifest, SVG_ID};
+use shared::symbols::SymbolCatalogue;
+
+/// A symbol's glyph: typeset by MathJax where the rendering manifest says it can be, otherwise the
+/// SVG render in the current text colour, with the PNG render as a last resort.
+#[component]
+pub fn SymbolGlyph(symbol: String, #[prop(default = 35)] size: u32) -> impl IntoView {
+    let entry = SymbolCatalogue::embedded().get(&symbol);
+    // Model labels can be aliases, but renders are keyed by the catalogue id
+    let key = entry.map(|entry| entry.id.clone()).unwrap_or(symbol);
+    let command = entry.map(|entry| entry.command.clone()).unwrap_or_else(|| key.clone());
+    let support = RenderingManifest::embedded().get(&key);
+
+    // With no render at all, MathJax is still the best guess
+    if support.mathjax || !(support.svg || support.png) {
+        let latex_content = format!("${command}$");
+        view! {
+            <div inner_html={format!(r#"<math-jax style="font-size: {size}px;">{}</math-jax>"#, latex_content)}></div>
+        }.into_any()
+    } else if support.svg {
+        // `<use>` keeps the SVG in the page's styling, so its currentColor fill follows the text colour
+        let url = format!("{}#{SVG_ID}", render_url(&key, "svg"));
+        view! {
+            <div
+                role="img"
+                aria-label=command
+                inner_html={format!(r#"<svg class="glyph-svg" width="{size}" height="{size}"><use href="{url}" width="100%" height="100%"/></svg>"#)}
+            ></div>
+        }.into_any()
+    } else {
+        let url = render_url(&key, "png");
+        view! {
+            <div>
+                <img src={url} alt={command} style={format!("width: {size}px; height: {size}px;")} />
+            </div>
+        }.into_any()
+    }
+}
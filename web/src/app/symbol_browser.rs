@@ -0,0 +1,142 @@
+use crate::app::glyph::SymbolGlyph;
+use crate::app::symbol_detail::symbol_path;
+use leptos::control_flow::For;
+use leptos::ev;
+use leptos::prelude::{event_target_value, signal, ClassAttribute, Effect, ElementChild, Get, GetUntracked, Memo, NodeRef, NodeRefAttribute, OnAttribute, PropAttribute, Set, StyleAttribute};
+use leptos::wasm_bindgen::JsCast;
+use leptos::{component, view, IntoView};
+use leptos_meta::Title;
+use shared::search::search;
+use shared::symbols::SymbolCatalogue;
+
+// Rows are a fixed height so the visible slice can be computed from the scroll offset alone
+const ROW_HEIGHT: f64 = 56.0;
+const VIEWPORT_HEIGHT: f64 = 560.0;
+// Rows rendered beyond each edge of the viewport, so fast scrolling doesn't show gaps
+const OVERSCAN: usize = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ModeFilter {
+    All,
+    Math,
+    Text
+}
+
+/// Every catalogue entry, searchable and filterable, rendering only the rows in view.
+#[component]
+pub fn SymbolBrowser() -> impl IntoView {
+    let catalogue = SymbolCatalogue::embedded();
+    let list_ref = NodeRef::<leptos::html::Div>::new();
+    let (query, set_query) = signal(String::new());
+    // Empty means every package
+    let (package, set_package) = signal(String::new());
+    let (mode, set_mode) = signal(ModeFilter::All);
+    let (scroll_top, set_scroll_top) = signal(0.0);
+
+    let mut packages: Vec<String> = catalogue.iter()
+        .map(|symbol| symbol.package.clone())
+        .collect();
+    packages.sort();
+    packages.dedup();
+
+    let results = Memo::new(move |_| {
+        let package = package.get();
+        let mode = mode.get();
+        search(catalogue, &query.get())
+            .into_iter()
+            .filter(|symbol| package.is_empty() || symbol.package == package)
+            .filter(|symbol| match mode {
+                ModeFilter::All => true,
+                ModeFilter::Math => symbol.mathmode,
+                ModeFilter::Text => symbol.textmode
+            })
+            .map(|symbol| symbol.id.clone())
+            .collect::<Vec<String>>()
+    });
+
+    // A new result set starts from the top
+    Effect::new(move |_| {
+        results.get();
+        set_scroll_top.set(0.0);
+        if let Some(list) = list_ref.get_untracked() {
+            list.set_scroll_top(0);
+        }
+    });
+
+    let visible_rows = move || {
+        let first = ((scroll_top.get() / ROW_HEIGHT) as usize).saturating_sub(OVERSCAN);
+        let count = (VIEWPORT_HEIGHT / ROW_HEIGHT).ceil() as usize + 2 * OVERSCAN;
+        results.get()
+            .into_iter()
+            .enumerate()
+            .skip(first)
+            .take(count)
+            .collect::<Vec<_>>()
+    };
+
+    let on_scroll = move |e: ev::Event| {
+        if let Some(list) = e.target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+            set_scroll_top.set(list.scroll_top() as f64);
+        }
+    };
+
+    view! {
+        <Title text="LaTeX Symbols"/>
+        <h1>"LaTeX Symbols"</h1>
+        <p><a href="/">"Draw a symbol instead"</a></p>
+        <div class="symbol-filters">
+            <input
+                type="search"
+                placeholder="Search: arrow, integral, greek, \\alpha…"
+                prop:value=query
+                on:input=move |e| set_query.set(event_target_value(&e))
+            />
+            <select on:change=move |e| set_package.set(event_target_value(&e))>
+                <option value="">"All packages"</option>
+                {packages.into_iter().map(|package| view! { <option value=package.clone()>{package.clone()}</option> }).collect::<Vec<_>>()}
+            </select>
+            <select on:change=move |e| set_mode.set(match event_target_value(&e).as_str() {
+                "math" => ModeFilter::Math,
+                "text" => ModeFilter::Text,
+                _ => ModeFilter::All
+            })>
+                <option value="all">"Math and text"</option>
+                <option value="math">"Math mode"</option>
+                <option value="text">"Text mode"</option>
+            </select>
+        </div>
+        <p class="symbol-count">{move || format!("{} symbols", results.get().len())}</p>
+        <div
+            class="symbol-list"
+            node_ref=list_ref
+            style=format!("height: {VIEWPORT_HEIGHT}px;")
+            on:scroll=on_scroll
+        >
+            <div style=move || format!("position: relative; height: {}px;", results.get().len() as f64 * ROW_HEIGHT)>
+                <For
+                    each=visible_rows
+                    key=|(index, key)| (*index, key.clone())
+                    children=move |(index, key)| {
+                        let symbol = catalogue.get(&key).expect("Search results come from the catalogue");
+                        let modes = match (symbol.mathmode, symbol.textmode) {
+                            (true, true) => "math, text",
+                            (true, false) => "math",
+                            _ => "text"
+                        };
+                        view! {
+                            <div
+                                class="symbol-row"
+                                style=format!("top: {}px; height: {ROW_HEIGHT}px;", index as f64 * ROW_HEIGHT)
+                            >
+                                <div class="symbol-row-glyph"><SymbolGlyph symbol=key.clone() size=24/></div>
+                                <a href=symbol_path(&key)><strong>{symbol.command.clone()}</strong></a>
+                                <span class="symbol-package">{symbol.package.clone()}</span>
+                                <span class="symbol-mode">{modes}</span>
+                            </div>
+                        }
+                    }
+                />
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,137 @@
+use crate::app::glyph::SymbolGlyph;
+use leptos::prelude::{ClassAttribute, ElementChild, Get, IntoAny, Read, Resource, ServerFnError, Suspense};
+use leptos::{component, server, view, IntoView};
+use leptos_meta::Title;
+use leptos_router::hooks::use_params_map;
+use shared::prototypes::Neighbour;
+use shared::rendering::percent_encode;
+use shared::symbols::SymbolCatalogue;
+use shared::unicode::unicode_equivalent;
+
+/// The detail page URL for `key`; keys contain `\`, `/` and brackets, so everything else is percent-encoded.
+pub fn symbol_path(key: &str) -> String {
+    format!("/symbol/{}", percent_encode(key))
+}
+
+fn percent_decode(encoded: &str) -> String {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = encoded.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// The classes the model's prototypes place closest to `key`.
+#[server]
+pub async fn closest_symbols(key: String) -> Result<Vec<Neighbour>, ServerFnError> {
+    use burn::config::Config;
+    use shared::prototypes::PrototypeIndex;
+    use std::sync::OnceLock;
+
+    // How many look-alikes to list on each page
+    const CLOSEST_SYMBOLS: usize = 8;
+    // Copied from `models/` after training; see the README
+    static PROTOTYPES_ENCODED: &[u8] = include_bytes!("../../../prototypes.json");
+    static PROTOTYPES: OnceLock<PrototypeIndex> = OnceLock::new();
+
+    let index = PROTOTYPES.get_or_init(|| {
+        PrototypeIndex::load_binary(PROTOTYPES_ENCODED).expect("Failed to decode prototypes")
+    });
+    Ok(index.nearest_to_class(&key, CLOSEST_SYMBOLS))
+}
+
+/// Everything about one symbol: how it looks, how to type it and what it gets mistaken for.
+#[component]
+pub fn SymbolDetail() -> impl IntoView {
+    let params = use_params_map();
+    // The router may hand the key over still percent-encoded
+    let key = move || {
+        let raw = params.read().get("key").unwrap_or_default();
+        let catalogue = SymbolCatalogue::embedded();
+        if catalogue.get(&raw).is_some() { raw } else { percent_decode(&raw) }
+    };
+    let closest = Resource::new(key, closest_symbols);
+
+    move || {
+        let key = key();
+        let Some(symbol) = SymbolCatalogue::embedded().get(&key) else {
+            return view! {
+                <Title text="Unknown symbol"/>
+                <h1>"Unknown symbol"</h1>
+                <p>"There's no symbol called " <code>{key}</code> ". " <a href="/symbols">"Browse all symbols"</a></p>
+            }.into_any();
+        };
+
+        let modes = match (symbol.mathmode, symbol.textmode) {
+            (true, true) => "Math and text",
+            (true, false) => "Math only",
+            _ => "Text only"
+        };
+        let package = match symbol.package.as_str() {
+            "latex2e" => "None (LaTeX2e)".to_string(),
+            package => package.to_string()
+        };
+        let unicode = unicode_equivalent(&symbol.command);
+
+        view! {
+            <Title text=format!("{} – LaTeX Symbol", symbol.command)/>
+            <p><a href="/symbols">"All symbols"</a> " · " <a href="/">"Draw a symbol"</a></p>
+            <div class="symbol-detail">
+                <div class="symbol-detail-glyph"><SymbolGlyph symbol=key.clone() size=96/></div>
+                <h1><code>{symbol.command.clone()}</code></h1>
+                <table class="symbol-facts">
+                    <tr><th>"Package"</th><td>{package}</td></tr>
+                    <tr><th>"Font encoding"</th><td>{symbol.encoding().to_string()}</td></tr>
+                    <tr><th>"Mode"</th><td>{modes}</td></tr>
+                    <tr>
+                        <th>"Unicode"</th>
+                        <td>{unicode.as_ref().map(|unicode| format!("{} {}", unicode.character, unicode.code_point())).unwrap_or_else(|| "None".to_string())}</td>
+                    </tr>
+                    <tr>
+                        <th><code>"unicode-math"</code></th>
+                        <td>{unicode.and_then(|unicode| unicode.unicode_math).unwrap_or_else(|| "None".to_string())}</td>
+                    </tr>
+                </table>
+                <h2>"Example"</h2>
+                <pre class="example-document">{symbol.example_document()}</pre>
+                <h2>"Often confused with"</h2>
+                <Suspense fallback=|| view! { <p>"Loading…"</p> }>
+                    {move || closest.get().map(|result| match result {
+                        Ok(neighbours) => view! {
+                            <ul class="closest-symbols">
+                                {neighbours.into_iter().map(|neighbour| {
+                                    let command = SymbolCatalogue::embedded()
+                                        .get(&neighbour.key)
+                                        .map(|symbol| symbol.command.clone())
+                                        .unwrap_or_else(|| neighbour.key.clone());
+                                    view! {
+                                        <li>
+                                            <a href=symbol_path(&neighbour.key)>
+                                                <SymbolGlyph symbol=neighbour.key.clone() size=24/>
+                                                <code>{command}</code>
+                                            </a>
+                                            <span class="similarity">{format!("{:.0}% similar", neighbour.similarity * 100.0)}</span>
+                                        </li>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        }.into_any(),
+                        Err(_) => view! { <p>"Couldn't load similar symbols."</p> }.into_any()
+                    })}
+                </Suspense>
+            </div>
+        }.into_any()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::api::{classify_response, validate_k, ApiState, ClassifyResponse, DEFAULT_K, MAX_K};
+use serde::Serialize;
+use shared::image_processing::{image_from_bytes, rasterize_strokes};
+use shared::item::{HEIGHT, WIDTH};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Instant;
+
+const USAGE: &str = "Usage: web batch <directory> [--k N]";
+
+/// One line of batch output, labelled by its file path or its position in the request.
+#[derive(Serialize)]
+pub struct BatchLine<I: Serialize> {
+    pub input: I,
+    #[serde(flatten)]
+    pub result: ClassifyResponse
+}
+
+/// Classifies `images` in batches; each result's `latency_ms` is its share of the total.
+/// Returns the results in input order and the total time in milliseconds.
+pub async fn classify_all(state: &ApiState, images: &[[[f32; WIDTH]; HEIGHT]], k: usize) -> (Vec<ClassifyResponse>, f64) {
+    let start = Instant::now();
+    let classifications = state.classify_batch(images, k).await;
+    let total_ms = start.elapsed().as_secs_f64() * 1000.0;
+    let per_input_ms = total_ms / images.len().max(1) as f64;
+
+    let results = classifications.into_iter()
+        .map(|classification| classify_response(classification, per_input_ms))
+        .collect();
+    (results, total_ms)
+}
+
+/// PNG crops and detexify stroke JSON files under `directory`, sorted so output order is stable.
+fn collect_inputs(directory: &Path, inputs: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_inputs(&path, inputs)?;
+        } else if matches!(path.extension().and_then(|extension| extension.to_str()), Some("png" | "json")) {
+            inputs.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn read_input(path: &Path) -> Result<[[f32; WIDTH]; HEIGHT], String> {
+    let bytes = std::fs::read(path).map_err(|error| error.to_string())?;
+    if path.extension().is_some_and(|extension| extension == "png") {
+        image_from_bytes(&bytes).map_err(|error| error.to_string())
+    } else {
+        let strokes: Vec<Vec<[f32; 3]>> = serde_json::from_slice(&bytes).map_err(|error| error.to_string())?;
+        Ok(rasterize_strokes(&strokes))
+    }
+}
+
+/// Reports a bad command line and exits with the conventional status 2.
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    exit(2)
+}
+
+/// `web batch <directory> [--k N]`: classifies every `.png` and `.json` file under the directory,
+/// writing JSONL to stdout and timing to stderr.
+pub async fn run_cli(args: &[String]) {
+    let Some(directory) = args.first() else { usage_error("Missing the input directory") };
+    // Same bounds as the HTTP API's `k`
+    let k = match args.iter().position(|arg| arg == "--k") {
+        Some(index) => {
+            let Some(value) = args.get(index + 1) else { usage_error("--k needs a value") };
+            value.parse().ok()
+                .and_then(|k| validate_k(Some(k)).ok())
+                .unwrap_or_else(|| usage_error(&format!("--k must be a number between 1 and {MAX_K}")))
+        }
+        None => DEFAULT_K
+    };
+
+    let mut paths = Vec::new();
+    if let Err(error) = collect_inputs(Path::new(directory), &mut paths) {
+        eprintln!("Failed to read {directory}: {error}");
+        exit(1);
+    }
+
+    let mut images = Vec::with_capacity(paths.len());
+    let mut labels = Vec::with_capacity(paths.len());
+    for path in &paths {
+        match read_input(path) {
+            Ok(image) => {
+                images.push(image);
+                labels.push(path.display().to_string());
+            }
+            Err(error) => eprintln!("[WARN] Skipping {}: {error}", path.display())
+        }
+    }
+
+    let state = ApiState::new();
+    // The first batch pays for loading the model, which would skew the timing
+    state.classify_batch(&images[..images.len().min(1)], k).await;
+    let (results, total_ms) = classify_all(&state, &images, k).await;
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    for (input, result) in labels.into_iter().zip(results) {
+        let line = serde_json::to_string(&BatchLine { input, result }).expect("Failed to serialise a result");
+        writeln!(out, "{line}").expect("Failed to write to stdout");
+    }
+
+    eprintln!(
+        "Classified {} inputs in {total_ms:.1} ms ({:.2} ms each, {:.0} per second)",
+        images.len(),
+        total_ms / images.len().max(1) as f64,
+        images.len() as f64 / (total_ms / 1000.0).max(f64::EPSILON)
+    );
+}
@@ -1,5 +1,9 @@
 #![recursion_limit = "256"]
 pub mod app;
+#[cfg(feature = "ssr")]
+pub mod api;
+#[cfg(feature = "ssr")]
+pub mod batch;
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
@@ -7,3 +11,11 @@ pub fn hydrate() {
     console_error_panic_hook::set_once();
     leptos::mount::hydrate_body(App);
 }
+
+/// Runs inside `inference-worker.js`, keeping the forward pass off the UI thread.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn start_inference_worker() {
+    console_error_panic_hook::set_once();
+    crate::app::classifier::worker::start();
+}
@@ -8,6 +8,13 @@ async fn main() {
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
 
+    // `web batch <directory>` classifies files offline instead of serving
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "batch") {
+        web::batch::run_cli(&args[1..]).await;
+        return;
+    }
+
     let conf = get_configuration(Some("./leptos_options.toml")).unwrap();
     // Render sets the PORT environment variable
     let mut leptos_options = conf.leptos_options;
@@ -28,6 +35,7 @@ async fn main() {
     let routes = generate_route_list(App);
 
     let app = Router::new()
+        .merge(web::api::router())
         .leptos_routes(&leptos_options, routes, {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
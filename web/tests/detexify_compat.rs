@@ -0,0 +1,66 @@
+#![cfg(feature = "ssr")]
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use burn::config::Config;
+use serde::Deserialize;
+use shared::labels::Labels;
+use shared::symbols::SymbolCatalogue;
+use tower::ServiceExt;
+
+// Form bodies in the exact shape detexify's web client posts to `/classify`
+static RECORDINGS: &str = include_str!("recordings/detexify_classify.jsonl");
+// The model's classes, which `/classify` ranks; see the README on copying training artifacts
+static LABELS_ENCODED: &[u8] = include_bytes!("../../labels.json");
+
+#[derive(Deserialize)]
+struct Recording {
+    name: String,
+    body: String
+}
+
+#[derive(Deserialize)]
+struct Score {
+    id: String,
+    score: f32
+}
+
+async fn post_classify(body: String) -> (StatusCode, Vec<u8>) {
+    let request = Request::post("/classify")
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(body))
+        .unwrap();
+    let response = web::api::router::<()>().oneshot(request).await.unwrap();
+    let status = response.status();
+    (status, to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec())
+}
+
+#[tokio::test]
+#[ignore = "needs the trained model.bin, calibration.json and labels.json at the repository root"]
+async fn recorded_requests_get_detexify_shaped_responses() {
+    let catalogue = SymbolCatalogue::embedded();
+    let labels = Labels::load_binary(LABELS_ENCODED).expect("Failed to decode labels");
+    for line in RECORDINGS.lines().filter(|line| !line.trim().is_empty()) {
+        let recording: Recording = serde_json::from_str(line).unwrap();
+        let (status, body) = post_classify(recording.body).await;
+        assert_eq!(status, StatusCode::OK, "{}", recording.name);
+
+        let scores: Vec<Score> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(scores.len(), labels.len(), "{}: every class should be ranked", recording.name);
+        assert!(scores.iter().all(|score| catalogue.get(&score.id).is_some()), "{}: unknown id", recording.name);
+        assert!(
+            scores.windows(2).all(|pair| pair[0].score <= pair[1].score),
+            "{}: scores should be ascending like detexify's distances",
+            recording.name
+        );
+    }
+}
+
+#[tokio::test]
+async fn malformed_strokes_are_rejected() {
+    let (status, _) = post_classify("strokes=not%20json".to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = post_classify("strokes=%5B%5D".to_string()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}